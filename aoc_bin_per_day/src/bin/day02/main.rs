@@ -0,0 +1,174 @@
+// @generated by aoc_gen from solutions/day02/solution.rs, edit that file instead
+#![feature(test)]
+extern crate test;
+
+use common::anyhow::{anyhow, Error, Result};
+use common::{Answer, FromLine, Solution};
+use std::str::FromStr;
+
+pub fn main() -> std::process::ExitCode {
+    common::cli::solution_main::<Day02>(2)
+}
+
+pub fn part_one(input: &str) -> Result<Answer> {
+    Ok(Day02::part_one(&Day02::parse(input)?)?.into())
+}
+
+pub fn part_two(input: &str) -> Result<Answer> {
+    Ok(Day02::part_two(&Day02::parse(input)?)?.into())
+}
+
+struct Day02;
+
+#[derive(Debug, FromLine)]
+#[line(regex = r"^Game (?P<id>\d+): (?P<sets>.*)$")]
+struct Game {
+    id: i128,
+    #[line(sep = ";")]
+    sets: Vec<GameSet>,
+}
+
+#[derive(Debug)]
+struct GameSet {
+    red: i128,
+    green: i128,
+    blue: i128,
+}
+
+#[derive(FromLine)]
+#[line("{} {}")]
+struct Cubes {
+    amount: i128,
+    color: String,
+}
+
+#[derive(Debug)]
+struct Input {
+    games: Vec<Game>,
+}
+
+impl Game {
+    fn is_valid(&self) -> bool {
+        self.sets.iter().all(|s| s.is_possible())
+    }
+    fn fewest_possible(&self) -> GameSet {
+        let mut max_red = 0;
+        let mut max_green = 0;
+        let mut max_blue = 0;
+        for set in self.sets.iter() {
+            max_red = std::cmp::max(max_red, set.red);
+            max_green = std::cmp::max(max_green, set.green);
+            max_blue = std::cmp::max(max_blue, set.blue);
+        }
+        GameSet {
+            red: max_red,
+            green: max_green,
+            blue: max_blue,
+        }
+    }
+}
+
+impl GameSet {
+    fn is_possible(&self) -> bool {
+        let max_red = 12;
+        let max_green = 13;
+        let max_blue = 14;
+        self.red <= max_red && self.green <= max_green && self.blue <= max_blue
+    }
+    fn power(&self) -> i128 {
+        self.red * self.green * self.blue
+    }
+}
+
+impl FromStr for GameSet {
+    type Err = Error;
+    fn from_str(set: &str) -> Result<GameSet> {
+        let mut red = 0;
+        let mut green = 0;
+        let mut blue = 0;
+        for cubes in set.split(",") {
+            let Cubes { amount, color } = cubes.trim().parse()?;
+            match color.as_str() {
+                "red" => red += amount,
+                "green" => green += amount,
+                "blue" => blue += amount,
+                _ => return Err(anyhow!("unknown color {:?} in {:?}", color, set)),
+            }
+        }
+        Ok(GameSet { red, green, blue })
+    }
+}
+
+impl Solution for Day02 {
+    type Input<'a> = Input;
+
+    fn parse(input: &str) -> Result<Input> {
+        let games = input
+            .lines()
+            .map(str::parse)
+            .collect::<Result<Vec<Game>>>()?;
+        Ok(Input { games })
+    }
+
+    fn part_one(input: &Self::Input<'_>) -> Result<impl Into<Answer>> {
+        let Input { games } = input;
+        Ok(games
+            .iter()
+            .filter(|g| g.is_valid())
+            .map(|g| g.id)
+            .sum::<i128>())
+    }
+
+    fn part_two(input: &Self::Input<'_>) -> Result<impl Into<Answer>> {
+        let Input { games } = input;
+        Ok(games
+            .iter()
+            .map(|g| g.fewest_possible().power())
+            .sum::<i128>())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::test_utils::*;
+    use std::sync::LazyLock;
+    use test::Bencher;
+
+    static TEST: LazyLock<String> = local_file!("test.txt");
+    static INPUT: LazyLock<String> = local_file!("input.txt");
+
+    #[test]
+    fn test_one() -> Result<()> {
+        let answer = super::part_one(&TEST)?;
+        assert_eq!(answer, Answer::Num(8));
+        Ok(())
+    }
+    #[test]
+    fn part_one() -> Result<()> {
+        let answer = super::part_one(&INPUT)?;
+        assert_eq!(answer, Answer::Num(2720));
+        Ok(())
+    }
+    #[test]
+    fn test_two() -> Result<()> {
+        let answer = super::part_two(&TEST)?;
+        assert_eq!(answer, Answer::Num(2286));
+        Ok(())
+    }
+    #[test]
+    fn part_two() -> Result<()> {
+        let answer = super::part_two(&INPUT)?;
+        assert_eq!(answer, Answer::Num(71535));
+        Ok(())
+    }
+
+    #[bench]
+    fn bench_part_one(b: &mut Bencher) {
+        b.iter(part_one)
+    }
+    #[bench]
+    fn bench_part_two(b: &mut Bencher) {
+        b.iter(part_two)
+    }
+}
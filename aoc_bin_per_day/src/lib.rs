@@ -1,14 +1,5 @@
-pub fn add(left: u64, right: u64) -> u64 {
-    left + right
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn it_works() {
-        let result = add(2, 2);
-        assert_eq!(result, 4);
-    }
-}
+// The library crate shared by the day binaries in src/bin (and the runner in
+// src/main.rs). The code itself lives in the repository wide common crate, so
+// this only re-exports it: Answer, regx!, the re-exported anyhow/regex and with
+// the test-utils feature (enabled for tests) local_file!.
+pub use common::*;
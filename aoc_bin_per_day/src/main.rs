@@ -0,0 +1,14 @@
+use aoc_bin_per_day::{
+    anyhow::Result,
+    orchestrator::{Args, Orchestrator},
+    solver::StructOpt,
+};
+use std::{path::Path, process::ExitCode};
+
+// Runs the day binaries of this package with the subcommands and --check of the other
+// overarching programs (there is no cache or trace of the day binaries). The days can't be called directly as every binary is its
+// own crate, so they are built by cargo and run as processes instead.
+fn main() -> Result<ExitCode> {
+    let mut orchestrator = Orchestrator::build(Path::new(env!("CARGO_MANIFEST_DIR")))?;
+    Ok(orchestrator.run(Args::from_args()))
+}
@@ -0,0 +1,421 @@
+use std::{
+    env, fs, io,
+    path::{Path, PathBuf},
+    process::ExitCode,
+};
+
+// Every day is written once in solutions/dayNN/solution.rs (next to its input and
+// example files) and copied into each layout. The copies only differ in the crate
+// attributes and the main of the binary layouts (see common::cli).
+
+enum Kind {
+    // a module of the crate containing all days (mod.rs)
+    Module,
+    // a binary crate with a stdin driven main (main.rs)
+    Binary,
+    // a library crate (lib.rs) with a stdin driven binary next to it (main.rs)
+    Library,
+}
+
+struct Layout {
+    // directory containing the day's source and data files, {day} is e.g. day03
+    dir: &'static str,
+    kind: Kind,
+}
+
+const LAYOUTS: [Layout; 4] = [
+    Layout {
+        dir: "aoc_module_per_day/days/src/{day}",
+        kind: Kind::Module,
+    },
+    Layout {
+        dir: "aoc_workspace/days_ws/{day}/src",
+        kind: Kind::Library,
+    },
+    Layout {
+        dir: "aoc_project_per_day/{day}/src",
+        kind: Kind::Binary,
+    },
+    Layout {
+        dir: "aoc_bin_per_day/src/bin/{day}",
+        kind: Kind::Binary,
+    },
+];
+
+const SOLUTIONS: &str = "solutions";
+const SOLUTION_FILE: &str = "solution.rs";
+
+const CRATE_ATTRIBUTES: &str = "\
+#![feature(test)]
+extern crate test;
+";
+
+// Marks an item of a solution (with the comments above it) that only the Solver runners
+// can reach, like a part taking &InputFile: the main of the binary layouts reads stdin
+// into a String, so their copies leave it out. The other copies keep it without the line.
+// A comment and not a #[cfg], there is no such cfg and rustc would warn about it.
+const SOLVER_ONLY: &str = "// aoc_gen: solver-only";
+
+// {number} is the day without leading zero, {day} e.g. day03, {main} one of the mains below
+const BINARY_MAIN: &str = "\
+pub fn main() -> std::process::ExitCode {
+    {main}
+}
+";
+
+const LIBRARY_RUN: &str = "\
+// the main of the binary next to this library, see common::cli
+pub fn run() -> std::process::ExitCode {
+    {main}
+}
+";
+
+// the day's parse_input, solve_one and solve_two, or a day implementing Solution ({type})
+const PARTS_MAIN: &str = "common::cli::main({number}, parse_input, solve_one, solve_two)";
+const SOLUTION_MAIN: &str = "common::cli::solution_main::<{type}>({number})";
+
+const LIBRARY_MAIN: &str = "\
+fn main() -> std::process::ExitCode {
+    {day}::run()
+}
+";
+
+struct Generated {
+    path: PathBuf,
+    content: Vec<u8>,
+}
+
+fn main() -> ExitCode {
+    let args: Vec<String> = env::args().skip(1).collect();
+    let check = match args.as_slice() {
+        [] => false,
+        [flag] if flag == "--check" => true,
+        _ => {
+            eprintln!("usage: aoc_gen [--check]");
+            return ExitCode::from(2);
+        }
+    };
+    let root = repository_root();
+    let result = if check {
+        check_files(&root)
+    } else {
+        write_files(&root)
+    };
+    match result {
+        Ok(true) => ExitCode::SUCCESS,
+        Ok(false) => ExitCode::FAILURE,
+        Err(err) => {
+            eprintln!("Err: {}", err);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn repository_root() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .parent()
+        .expect("aoc_gen lives inside the repository")
+        .to_path_buf()
+}
+
+fn write_files(root: &Path) -> io::Result<bool> {
+    for file in generate(root)? {
+        if fs::read(&file.path).ok().as_ref() == Some(&file.content) {
+            continue;
+        }
+        if let Some(dir) = file.path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        fs::write(&file.path, &file.content)?;
+        println!("wrote {}", relative(root, &file.path));
+    }
+    Ok(true)
+}
+
+fn check_files(root: &Path) -> io::Result<bool> {
+    let stale = stale_files(root)?;
+    for path in stale.iter() {
+        println!("out of date (hand-edited?): {}", relative(root, path));
+    }
+    if !stale.is_empty() {
+        println!("edit solutions/ instead and regenerate with `cargo run` in aoc_gen");
+    }
+    Ok(stale.is_empty())
+}
+
+fn stale_files(root: &Path) -> io::Result<Vec<PathBuf>> {
+    Ok(generate(root)?
+        .into_iter()
+        .filter(|file| fs::read(&file.path).ok().as_ref() != Some(&file.content))
+        .map(|file| file.path)
+        .collect())
+}
+
+fn generate(root: &Path) -> io::Result<Vec<Generated>> {
+    let mut files = Vec::new();
+    for day in days(root)? {
+        let day_dir = root.join(SOLUTIONS).join(&day);
+        let solution = fs::read_to_string(day_dir.join(SOLUTION_FILE))?;
+        let mut data_files = Vec::new();
+        for entry in fs::read_dir(&day_dir)? {
+            let path = entry?.path();
+            if path.is_file() && !path.ends_with(SOLUTION_FILE) {
+                data_files.push(path);
+            }
+        }
+        data_files.sort();
+        for layout in LAYOUTS.iter() {
+            let dir = root.join(layout.dir.replace("{day}", &day));
+            for (source, content) in render(layout, &day, &solution)? {
+                files.push(Generated {
+                    path: dir.join(source),
+                    content: content.into_bytes(),
+                });
+            }
+            for data_file in data_files.iter() {
+                files.push(Generated {
+                    path: dir.join(data_file.file_name().unwrap()),
+                    content: fs::read(data_file)?,
+                });
+            }
+        }
+    }
+    Ok(files)
+}
+
+fn days(root: &Path) -> io::Result<Vec<String>> {
+    let mut days = Vec::new();
+    for entry in fs::read_dir(root.join(SOLUTIONS))? {
+        let entry = entry?;
+        let name = entry.file_name().to_string_lossy().into_owned();
+        let is_day = name.len() == 5
+            && name.starts_with("day")
+            && name[3..].chars().all(|c| c.is_ascii_digit());
+        if is_day && entry.path().is_dir() {
+            days.push(name);
+        }
+    }
+    days.sort();
+    Ok(days)
+}
+
+// returns the source files of the day in the layout with their content
+fn render(layout: &Layout, day: &str, solution: &str) -> io::Result<Vec<(&'static str, String)>> {
+    let header = format!(
+        "// @generated by aoc_gen from {}/{}/{}, edit that file instead\n",
+        SOLUTIONS, day, SOLUTION_FILE
+    );
+    let number = day.trim_start_matches("day").trim_start_matches('0');
+    let main = match solution_type(solution) {
+        Some(day_type) => SOLUTION_MAIN.replace("{type}", day_type),
+        None => String::from(PARTS_MAIN),
+    };
+    let fill = |template: &str| {
+        template
+            .replace("{main}", &main)
+            .replace("{number}", number)
+            .replace("{day}", day)
+    };
+    let solution = &match layout.kind {
+        Kind::Binary => unregistered(&solver_only(day, solution, false)?),
+        _ => solver_only(day, solution, true)?,
+    };
+    Ok(match layout.kind {
+        Kind::Module => vec![("mod.rs", header + solution)],
+        Kind::Binary => {
+            let (imports, rest) = split_imports(day, solution)?;
+            let main = format!(
+                "{}{}\n{}{}\n{}",
+                header,
+                CRATE_ATTRIBUTES,
+                imports,
+                fill(BINARY_MAIN),
+                rest
+            );
+            vec![("main.rs", main)]
+        }
+        Kind::Library => {
+            let (imports, rest) = split_imports(day, solution)?;
+            let lib = format!(
+                "{}{}\n{}{}\n{}",
+                header,
+                CRATE_ATTRIBUTES,
+                imports,
+                fill(LIBRARY_RUN),
+                rest
+            );
+            let main = format!("{}{}", header, fill(LIBRARY_MAIN));
+            vec![("lib.rs", lib), ("main.rs", main)]
+        }
+    })
+}
+
+// the type of an `impl Solution for Day02` in the solution
+fn solution_type(solution: &str) -> Option<&str> {
+    solution.lines().find_map(|line| {
+        let rest = line.strip_prefix("impl Solution for ")?;
+        rest.split([' ', '{']).next()
+    })
+}
+
+// Nothing reads the registry of #[aoc] parts in the binary layouts (common::cli::main
+// gets the functions), so their copies leave out the attributes and common's macros
+// feature: the solution without #[aoc(..)] and #[aoc_parser] and their imports.
+fn unregistered(solution: &str) -> String {
+    let mut lines = Vec::new();
+    for line in solution.split_inclusive('\n') {
+        let trimmed = line.trim();
+        if trimmed.starts_with("#[aoc(") || trimmed == "#[aoc_parser]" {
+            continue;
+        }
+        let Some(items) = line
+            .strip_prefix("use common::{")
+            .and_then(|rest| rest.trim_end().strip_suffix("};"))
+        else {
+            lines.push(String::from(line));
+            continue;
+        };
+        let items: Vec<&str> = items
+            .split(", ")
+            .filter(|item| !["aoc", "aoc_parser"].contains(item))
+            .collect();
+        match items.as_slice() {
+            [] => {}
+            [item] => lines.push(format!("use common::{};\n", item)),
+            items => lines.push(format!("use common::{{{}}};\n", items.join(", "))),
+        }
+    }
+    lines.concat()
+}
+
+// the solution without the SOLVER_ONLY lines, or without the items they mark if !keep
+fn solver_only(day: &str, solution: &str, keep: bool) -> io::Result<String> {
+    let mut lines: Vec<&str> = Vec::new();
+    let mut source = solution.split_inclusive('\n');
+    while let Some(line) = source.next() {
+        if line.trim() != SOLVER_ONLY {
+            lines.push(line);
+            continue;
+        }
+        if keep {
+            continue;
+        }
+        let indent = &line[..line.len() - line.trim_start().len()];
+        while lines
+            .last()
+            .is_some_and(|line| line.trim_start().starts_with("//"))
+        {
+            lines.pop();
+        }
+        // the item ends with a `}` at its indentation or a one line `...;`
+        let end = source.by_ref().find(|line| {
+            let item = line.trim_end().strip_prefix(indent).unwrap_or_default();
+            item == "}" || (!item.starts_with([' ', '#', '/']) && item.ends_with(';'))
+        });
+        if end.is_none() {
+            return Err(io::Error::other(format!(
+                "{}: no end of the item after {}",
+                day, SOLVER_ONLY
+            )));
+        }
+        // with the empty line after it
+        if source
+            .clone()
+            .next()
+            .is_some_and(|line| line.trim().is_empty())
+        {
+            source.next();
+        }
+    }
+    Ok(lines.concat())
+}
+
+// main goes between the imports and the first part (including its attributes)
+fn split_imports<'a>(day: &str, solution: &'a str) -> io::Result<(&'a str, &'a str)> {
+    let mut split = solution
+        .find("pub fn part_one")
+        .ok_or_else(|| io::Error::other(format!("{}: no `pub fn part_one` found", day)))?;
+    while let Some(line_start) = solution[..split].trim_end_matches('\n').rfind('\n') {
+        if !solution[line_start + 1..split].starts_with("#[") {
+            break;
+        }
+        split = line_start + 1;
+    }
+    Ok(solution.split_at(split))
+}
+
+fn relative(root: &Path, path: &Path) -> String {
+    path.strip_prefix(root)
+        .unwrap_or(path)
+        .display()
+        .to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn generated_files_are_up_to_date() {
+        let root = repository_root();
+        let stale = stale_files(&root).unwrap();
+        assert!(stale.is_empty(), "run aoc_gen, stale files: {:?}", stale);
+    }
+    #[test]
+    fn unregistered_parts() {
+        let solution = "\
+use common::{aoc, aoc_parser, Answer};
+use common::{aoc, aoc_parser};
+use common::{aoc, parse, Answer};
+
+#[aoc_parser]
+fn parse_input(input: &str) -> Result<Input> {}
+
+#[aoc(day = 5, part = 1)]
+fn solve_one(input: &Input) -> Result<Answer> {}
+";
+        assert_eq!(
+            unregistered(solution),
+            "\
+use common::Answer;
+use common::{parse, Answer};
+
+fn parse_input(input: &str) -> Result<Input> {}
+
+fn solve_one(input: &Input) -> Result<Answer> {}
+"
+        );
+    }
+    #[test]
+    fn solver_only_items() {
+        let solution = "\
+use a;
+// aoc_gen: solver-only
+use b;
+
+fn one() {}
+
+// mapped
+// aoc_gen: solver-only
+#[aoc(day = 5, part = 1)]
+fn two() {
+    x;
+}
+
+mod tests {
+    // aoc_gen: solver-only
+    #[test]
+    fn two() {
+    }
+}
+";
+        let kept = solver_only("day05", solution, true).unwrap();
+        assert!(!kept.contains(SOLVER_ONLY));
+        assert!(kept.contains("use b;\n") && kept.contains("// mapped\n#[aoc"));
+        assert_eq!(
+            solver_only("day05", solution, false).unwrap(),
+            "use a;\nfn one() {}\n\nmod tests {\n}\n"
+        );
+        assert!(solver_only("day05", "// aoc_gen: solver-only\nfn two() {\n", false).is_err());
+    }
+}
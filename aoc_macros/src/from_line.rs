@@ -0,0 +1,171 @@
+use proc_macro2::TokenStream;
+use quote::{quote, quote_spanned};
+use regex_syntax::hir::{Hir, HirKind};
+use syn::{
+    parse::ParseStream, spanned::Spanned, Attribute, Data, DeriveInput, Error, Field, Fields,
+    GenericArgument, Ident, LitStr, PathArguments, Token, Type,
+};
+
+enum Pattern {
+    Scan(LitStr),
+    Regex(LitStr),
+}
+
+pub fn derive(input: DeriveInput) -> syn::Result<TokenStream> {
+    let pattern = pattern(&input)?;
+    let fields = match &input.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Named(fields) => &fields.named,
+            _ => return Err(Error::new(input.ident.span(), "expected named fields")),
+        },
+        _ => return Err(Error::new(input.ident.span(), "expected a struct")),
+    };
+    let captures = match &pattern {
+        Pattern::Scan(pattern) => {
+            let placeholders = pattern.value().matches("{}").count();
+            if placeholders != fields.len() {
+                let message = format!(
+                    "the pattern has {} {{}} for {} fields",
+                    placeholders,
+                    fields.len()
+                );
+                return Err(Error::new(pattern.span(), message));
+            }
+            quote! {
+                let captures = ::common::scan::captures(#pattern, line)?;
+            }
+        }
+        Pattern::Regex(pattern) => {
+            let hir = regex_syntax::Parser::new()
+                .parse(&pattern.value())
+                .map_err(|err| Error::new(pattern.span(), format!("invalid regex: {}", err)))?;
+            let mut groups = Vec::new();
+            group_names(&hir, &mut groups);
+            for field in fields {
+                let ident = field.ident.as_ref().unwrap();
+                if !groups.contains(&ident.to_string()) {
+                    let message = format!("no group (?P<{}>..) in the regex", ident);
+                    return Err(Error::new(ident.span(), message));
+                }
+            }
+            quote! {
+                static RE: ::std::sync::LazyLock<::common::regex::Regex> =
+                    ::std::sync::LazyLock::new(|| ::common::regex::Regex::new(#pattern).unwrap());
+                let captures = RE.captures(line).ok_or_else(|| {
+                    ::common::anyhow::anyhow!("{:?} doesn't match {:?}", line, #pattern)
+                })?;
+            }
+        }
+    };
+    let mut values = Vec::new();
+    for (i, field) in fields.iter().enumerate() {
+        let ident = field.ident.as_ref().unwrap();
+        let name = ident.to_string();
+        let capture = match &pattern {
+            Pattern::Scan(_) => quote!(captures[#i]),
+            Pattern::Regex(_) => quote!(captures.name(#name).map_or("", |m| m.as_str())),
+        };
+        let ty = &field.ty;
+        let value = match (vec_item(ty), separator(field)?) {
+            (Some(item), sep) => {
+                let sep = match sep {
+                    Some(sep) => quote!(::std::option::Option::Some(#sep)),
+                    None => quote!(::std::option::Option::None),
+                };
+                quote_spanned!(ty.span()=> ::common::scan::items::<#item>(line, #name, #capture, #sep)?)
+            }
+            (None, Some(sep)) => {
+                return Err(Error::new(sep.span(), "`sep` only applies to Vec fields"));
+            }
+            (None, None) => {
+                quote_spanned!(ty.span()=> ::common::scan::field::<#ty>(line, #name, #capture)?)
+            }
+        };
+        values.push(quote!(#ident: #value));
+    }
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    Ok(quote! {
+        impl #impl_generics ::std::str::FromStr for #name #ty_generics #where_clause {
+            type Err = ::common::anyhow::Error;
+            fn from_str(line: &str) -> ::common::anyhow::Result<Self> {
+                #captures
+                ::std::result::Result::Ok(Self { #(#values),* })
+            }
+        }
+    })
+}
+
+// #[line("Card {}: {} | {}")] or #[line(regex = r"Card +(?P<id>\d+): ..")]
+fn pattern(input: &DeriveInput) -> syn::Result<Pattern> {
+    let Some(attr) = line_attribute(&input.attrs) else {
+        return Err(Error::new(
+            input.ident.span(),
+            "missing #[line(\"..\")] or #[line(regex = \"..\")]",
+        ));
+    };
+    attr.parse_args_with(|input: ParseStream| {
+        if input.peek(LitStr) {
+            return Ok(Pattern::Scan(input.parse()?));
+        }
+        let key: Ident = input.parse()?;
+        if key != "regex" {
+            return Err(Error::new(
+                key.span(),
+                "expected \"..\" or `regex = \"..\"`",
+            ));
+        }
+        input.parse::<Token![=]>()?;
+        Ok(Pattern::Regex(input.parse()?))
+    })
+}
+
+// #[line(sep = ";")] on a Vec field, which is split at whitespace otherwise
+fn separator(field: &Field) -> syn::Result<Option<LitStr>> {
+    let Some(attr) = line_attribute(&field.attrs) else {
+        return Ok(None);
+    };
+    let mut sep = None;
+    attr.parse_nested_meta(|meta| {
+        if !meta.path.is_ident("sep") {
+            return Err(meta.error("expected `sep = \"..\"`"));
+        }
+        sep = Some(meta.value()?.parse()?);
+        Ok(())
+    })?;
+    Ok(sep)
+}
+
+fn line_attribute(attrs: &[Attribute]) -> Option<&Attribute> {
+    attrs.iter().find(|attr| attr.path().is_ident("line"))
+}
+
+fn vec_item(ty: &Type) -> Option<&Type> {
+    let Type::Path(path) = ty else {
+        return None;
+    };
+    let segment = path.path.segments.last()?;
+    let PathArguments::AngleBracketed(args) = &segment.arguments else {
+        return None;
+    };
+    match (segment.ident == "Vec", args.args.first(), args.args.len()) {
+        (true, Some(GenericArgument::Type(item)), 1) => Some(item),
+        _ => None,
+    }
+}
+
+fn group_names(hir: &Hir, names: &mut Vec<String>) {
+    match hir.kind() {
+        HirKind::Capture(capture) => {
+            if let Some(name) = &capture.name {
+                names.push(name.to_string());
+            }
+            group_names(&capture.sub, names);
+        }
+        HirKind::Concat(hirs) | HirKind::Alternation(hirs) => {
+            hirs.iter().for_each(|hir| group_names(hir, names));
+        }
+        HirKind::Repetition(repetition) => group_names(&repetition.sub, names),
+        _ => {}
+    }
+}
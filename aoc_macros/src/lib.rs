@@ -0,0 +1,323 @@
+use proc_macro::TokenStream;
+use proc_macro2::{Span, TokenStream as TokenStream2};
+use quote::{quote, quote_spanned};
+use syn::{
+    parse_macro_input, spanned::Spanned, DeriveInput, Error, FnArg, Item, ItemFn, ItemImpl, LitInt,
+    LitStr, ReturnType, Type,
+};
+
+mod from_line;
+
+// regx!(r"\d+") is a &'static Regex compiled on first use, the pattern is checked while
+// compiling the crate using it (regex panics on invalid patterns only when they are used).
+// The doc tests make sure a valid pattern compiles and an invalid one doesn't:
+///
+/// ```
+/// let numbers = common::regx!(r"(\d+)");
+/// ```
+///
+/// ```compile_fail
+/// let numbers = common::regx!(r"(\d+");
+/// ```
+#[proc_macro]
+pub fn regx(input: TokenStream) -> TokenStream {
+    let pattern = parse_macro_input!(input as LitStr);
+    if let Err(err) = regex_syntax::Parser::new().parse(&pattern.value()) {
+        let message = format!("invalid regex: {}", err);
+        return Error::new(pattern.span(), message)
+            .into_compile_error()
+            .into();
+    }
+    quote! {{
+        static RE: ::std::sync::LazyLock<::common::regex::Regex> =
+            ::std::sync::LazyLock::new(|| ::common::regex::Regex::new(#pattern).unwrap());
+        &RE
+    }}
+    .into()
+}
+
+// #[aoc(day = 5, part = 2)] registers a part with common::registry, from where
+// Solver::add_registered picks it up. The part either takes the raw input like the
+// functions passed to Solver::add
+//
+//     #[aoc(day = 5, part = 2)]
+//     pub fn part_two(input: &str) -> Result<Answer>
+//
+// or a reference to the output of the #[aoc_parser] in the same module
+//
+//     #[aoc_parser]
+//     fn parse_input(input: &str) -> Result<Input>
+//     #[aoc(day = 5, part = 2)]
+//     fn solve_two(input: &Input) -> Result<Answer>
+//
+// or the common::input::InputFile to read it without a String, which the Solver prefers
+// to a part taking the text
+//
+//     #[aoc(day = 5, part = 2)]
+//     fn solve_two_mapped(input: &InputFile) -> Result<Answer>
+//
+// A part may return anything convertible to Answer, e.g. Result<usize>. The function
+// itself is left as it is. The parse and solve steps of parsed parts are recorded as
+// common::trace spans, a part taking the InputFile records its own. On an `impl Solution for Day05` it registers both parts of the
+// day, without a part:
+//
+//     #[aoc(day = 5)]
+//     impl Solution for Day05 { .. }
+#[proc_macro_attribute]
+pub fn aoc(args: TokenStream, item: TokenStream) -> TokenStream {
+    let mut day = None;
+    let mut part = None;
+    let arg_parser = syn::meta::parser(|meta| {
+        let target = if meta.path.is_ident("day") {
+            &mut day
+        } else if meta.path.is_ident("part") {
+            &mut part
+        } else {
+            return Err(meta.error("expected `day = ..` or `part = ..`"));
+        };
+        *target = Some(meta.value()?.parse::<LitInt>()?);
+        Ok(())
+    });
+    parse_macro_input!(args with arg_parser);
+    let item = parse_macro_input!(item as Item);
+    let registration = match &item {
+        Item::Fn(function) => register(day, part, function),
+        Item::Impl(solution) => register_solution(day, part, solution),
+        item => Err(Error::new(
+            item.span(),
+            "expected a part fn or an `impl Solution for ..`",
+        )),
+    };
+    let registration = registration.unwrap_or_else(Error::into_compile_error);
+    quote!(#item #registration).into()
+}
+
+// marks the parser whose output is passed to the parts taking something else than &str
+#[proc_macro_attribute]
+pub fn aoc_parser(args: TokenStream, item: TokenStream) -> TokenStream {
+    let function = parse_macro_input!(item as ItemFn);
+    let alias = parser_alias(args.into(), &function).unwrap_or_else(Error::into_compile_error);
+    quote!(#function #alias).into()
+}
+
+// #[derive(FromLine)] implements FromStr for a struct from a scan! style pattern, the
+// {} are the fields in order
+//
+//     #[derive(FromLine)]
+//     #[line("Card {}: {} | {}")]
+//     struct Card { id: usize, winners: Vec<usize>, numbers: Vec<usize> }
+//
+// or a regex with a named group per field (needs common's regex feature)
+//
+//     #[derive(FromLine)]
+//     #[line(regex = r"^Game (?P<id>\d+): (?P<sets>.*)$")]
+//     struct Game { id: u32, #[line(sep = ";")] sets: Vec<GameSet> }
+//
+// Vec fields are split at whitespace or at `sep` and every item is parsed on its own,
+// so nested FromLine structs work. Errors name the field (and item) and the line.
+#[proc_macro_derive(FromLine, attributes(line))]
+pub fn from_line(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    from_line::derive(input)
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
+fn register(
+    day: Option<LitInt>,
+    part: Option<LitInt>,
+    function: &ItemFn,
+) -> syn::Result<TokenStream2> {
+    let day = number(day, "day", 1..=25)?;
+    let part = number(part, "part", 1..=2)?;
+    let name = &function.sig.ident;
+    let call = match (input_type(function)?, &function.sig.output) {
+        (_, ReturnType::Default) => {
+            return Err(Error::new(
+                function.sig.span(),
+                "a part returns Result<Answer> (or anything else Into<Answer>)",
+            ));
+        }
+        (InputType::Raw, ReturnType::Type(_, output)) => {
+            quote_spanned!(output.span()=> #name(input).map(::core::convert::Into::into))
+        }
+        (InputType::Source, ReturnType::Type(_, output)) => {
+            let call =
+                quote_spanned!(output.span()=> #name(input).map(::core::convert::Into::into));
+            return Ok(quote! {
+                const _: () = {
+                    // the answer may already be an Answer
+                    #[allow(clippy::useless_conversion)]
+                    fn part(
+                        input: &::common::input::InputFile,
+                    ) -> ::common::anyhow::Result<::common::Answer> {
+                        #call
+                    }
+                    ::common::registry::inventory::submit! {
+                        ::common::registry::SourceRegistration { day: #day, part: #part, fun: part }
+                    }
+                };
+            });
+        }
+        (InputType::Parsed(span), ReturnType::Type(_, output)) => {
+            // without a parser the error points at the input type
+            let parsed = quote_spanned!(span=> __aoc_parser(input)?);
+            let solve =
+                quote_spanned!(output.span()=> #name(&parsed).map(::core::convert::Into::into));
+            quote! {
+                let parsed = {
+                    let _span = ::common::trace::span("parse");
+                    #parsed
+                };
+                let _span = ::common::trace::span("solve");
+                #solve
+            }
+        }
+    };
+    Ok(quote! {
+        const _: () = {
+            // the answer may already be an Answer
+            #[allow(clippy::useless_conversion)]
+            fn part(input: &str) -> ::common::anyhow::Result<::common::Answer> {
+                #call
+            }
+            ::common::registry::inventory::submit! {
+                ::common::registry::Registration { day: #day, part: #part, fun: part }
+            }
+        };
+    })
+}
+
+fn register_solution(
+    day: Option<LitInt>,
+    part: Option<LitInt>,
+    solution: &ItemImpl,
+) -> syn::Result<TokenStream2> {
+    let day = number(day, "day", 1..=25)?;
+    if let Some(part) = part {
+        return Err(Error::new(
+            part.span(),
+            "a Solution registers both parts, remove the part",
+        ));
+    }
+    let is_solution = solution
+        .trait_
+        .as_ref()
+        .is_some_and(|(_, path, _)| path.segments.last().unwrap().ident == "Solution");
+    if !is_solution || !solution.generics.params.is_empty() {
+        return Err(Error::new(
+            solution.self_ty.span(),
+            "expected `impl Solution for ..` without generics",
+        ));
+    }
+    let day_type = &solution.self_ty;
+    Ok(quote! {
+        ::common::registry::inventory::submit! {
+            ::common::registry::SolutionRegistration {
+                day: #day,
+                fun: ::common::solution_parts::<#day_type>,
+            }
+        }
+    })
+}
+
+fn parser_alias(args: TokenStream2, function: &ItemFn) -> syn::Result<TokenStream2> {
+    if !args.is_empty() {
+        return Err(Error::new(args.span(), "#[aoc_parser] takes no arguments"));
+    }
+    let span = match input_type(function)? {
+        InputType::Raw => None,
+        InputType::Source => Some(function.sig.inputs.span()),
+        InputType::Parsed(span) => Some(span),
+    };
+    if let Some(span) = span {
+        return Err(Error::new(span, "a parser takes the raw input: &str"));
+    }
+    if let ReturnType::Default = function.sig.output {
+        return Err(Error::new(
+            function.sig.span(),
+            "a parser returns Result<..>",
+        ));
+    }
+    let name = &function.sig.ident;
+    Ok(quote! {
+        #[allow(unused_imports)]
+        use #name as __aoc_parser;
+    })
+}
+
+enum InputType {
+    Raw,
+    Source,
+    Parsed(Span),
+}
+
+// the single argument has to be a shared reference, &str, &InputFile or the parsed input
+fn input_type(function: &ItemFn) -> syn::Result<InputType> {
+    let sig = &function.sig;
+    if sig.asyncness.is_some() || !sig.generics.params.is_empty() {
+        return Err(Error::new(
+            sig.span(),
+            "expected a plain fn(&..) -> Result<..>",
+        ));
+    }
+    let mut inputs = sig.inputs.iter();
+    let (Some(FnArg::Typed(arg)), None) = (inputs.next(), inputs.next()) else {
+        return Err(Error::new(
+            sig.inputs.span(),
+            "expected exactly one argument: &str or &Input",
+        ));
+    };
+    let Type::Reference(reference) = arg.ty.as_ref() else {
+        return Err(Error::new(
+            arg.ty.span(),
+            "expected a reference: &str or &Input",
+        ));
+    };
+    if reference.mutability.is_some() {
+        return Err(Error::new(arg.ty.span(), "the input can't be mutable"));
+    }
+    Ok(match reference.elem.as_ref() {
+        Type::Path(path) if path.qself.is_none() && path.path.is_ident("str") => InputType::Raw,
+        Type::Path(path) if path.qself.is_none() && is_input_file(&path.path) => InputType::Source,
+        elem => InputType::Parsed(elem.span()),
+    })
+}
+
+// common::input::InputFile as it is written, `InputFile`, `input::InputFile` or the whole
+// path: a macro doesn't see the imports, so an own type named InputFile can't be an input
+fn is_input_file(path: &syn::Path) -> bool {
+    if path
+        .segments
+        .iter()
+        .any(|segment| !segment.arguments.is_none())
+    {
+        return false;
+    }
+    let written: Vec<String> = path.segments.iter().map(|s| s.ident.to_string()).collect();
+    match written.join("::").as_str() {
+        "common::input::InputFile" => true,
+        "InputFile" | "input::InputFile" => path.leading_colon.is_none(),
+        _ => false,
+    }
+}
+
+fn number(
+    value: Option<LitInt>,
+    name: &str,
+    range: std::ops::RangeInclusive<u8>,
+) -> syn::Result<u8> {
+    let Some(value) = value else {
+        return Err(Error::new(
+            Span::call_site(),
+            format!("missing `{} = ..`, e.g. #[aoc(day = 5, part = 2)]", name),
+        ));
+    };
+    match value.base10_parse::<u8>() {
+        Ok(n) if range.contains(&n) => Ok(n),
+        _ => Err(Error::new(
+            value.span(),
+            format!("{} has to be in {}..={}", name, range.start(), range.end()),
+        )),
+    }
+}
@@ -0,0 +1,87 @@
+use common::{anyhow::Result, FromLine};
+
+#[derive(Debug, PartialEq, FromLine)]
+#[line("Card {}: {} | {}")]
+struct Card {
+    id: usize,
+    winners: Vec<u8>,
+    numbers: Vec<u8>,
+}
+
+#[derive(Debug, PartialEq, FromLine)]
+#[line(regex = r"^Game (?P<id>\d+): (?P<sets>.*)$")]
+struct Game {
+    id: u32,
+    #[line(sep = ";")]
+    sets: Vec<Set>,
+}
+
+#[derive(Debug, PartialEq, FromLine)]
+#[line("{}")]
+struct Set {
+    #[line(sep = ",")]
+    cubes: Vec<Cubes>,
+}
+
+#[derive(Debug, PartialEq, FromLine)]
+#[line("{} {}")]
+struct Cubes {
+    amount: u32,
+    color: String,
+}
+
+#[test]
+fn scan_pattern() -> Result<()> {
+    let card: Card = "Card   1: 41 48 83 | 83 86  6".parse()?;
+    assert_eq!(
+        card,
+        Card {
+            id: 1,
+            winners: vec![41, 48, 83],
+            numbers: vec![83, 86, 6],
+        }
+    );
+    let card: Card = "Card 2:  | 1".parse()?;
+    assert!(card.winners.is_empty());
+    Ok(())
+}
+
+#[test]
+fn nested_vecs() -> Result<()> {
+    let game: Game = "Game 3: 3 blue, 4 red; 2 green".parse()?;
+    assert_eq!(game.id, 3);
+    assert_eq!(game.sets.len(), 2);
+    assert_eq!(
+        game.sets[0].cubes[1],
+        Cubes {
+            amount: 4,
+            color: "red".into(),
+        }
+    );
+    assert_eq!(game.sets[1].cubes.len(), 1);
+    Ok(())
+}
+
+#[test]
+fn errors() {
+    let err = "Card x: 1 | 2".parse::<Card>().unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "field `id` \"x\" of \"Card x: 1 | 2\" is no usize: invalid digit found in string"
+    );
+    let err = "Card 1: 1 300 | 2".parse::<Card>().unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "field `winners` item 2 \"300\" of \"Card 1: 1 300 | 2\" is no u8: number too large to fit in target type"
+    );
+    let err = "Game 1 3 blue".parse::<Game>().unwrap_err();
+    assert!(err
+        .to_string()
+        .starts_with("\"Game 1 3 blue\" doesn't match"));
+    // the innermost failure, with the line it was part of
+    let err = "Game 1: 3 blue; x red".parse::<Game>().unwrap_err();
+    let message = err.to_string();
+    assert!(message.starts_with("field `sets` item 2 \"x red\" of \"Game 1: 3 blue; x red\""));
+    assert!(message
+        .ends_with("field `amount` \"x\" of \"x red\" is no u32: invalid digit found in string"));
+}
@@ -0,0 +1,111 @@
+use common::{
+    anyhow::{anyhow, Result},
+    aoc, aoc_parser,
+    input::InputFile,
+    registry::{registered, registered_solutions, registered_sources},
+    Answer, Solution,
+};
+
+#[aoc(day = 24, part = 1)]
+fn part_one(input: &str) -> Result<Answer> {
+    Ok(Answer::Num(input.len() as i128))
+}
+
+mod parsed {
+    use super::*;
+
+    struct Input {
+        numbers: Vec<i128>,
+    }
+
+    #[aoc_parser]
+    fn parse_input(input: &str) -> Result<Input> {
+        let numbers = input
+            .split_whitespace()
+            .map(|n| n.parse().map_err(|_| anyhow!("not a number: {}", n)))
+            .collect::<Result<_>>()?;
+        Ok(Input { numbers })
+    }
+
+    #[aoc(day = 24, part = 2)]
+    fn solve_two(input: &Input) -> Result<Answer> {
+        Ok(Answer::Num(input.numbers.iter().sum()))
+    }
+}
+
+// anything convertible to Answer
+#[aoc(day = 23, part = 1)]
+fn count(input: &str) -> Result<usize> {
+    Ok(input.split_whitespace().count())
+}
+
+struct Day22;
+
+#[aoc(day = 22)]
+impl Solution for Day22 {
+    type Input<'a> = Vec<&'a str>;
+    fn parse(input: &str) -> Result<Vec<&str>> {
+        Ok(input.lines().collect())
+    }
+    fn part_one(input: &Self::Input<'_>) -> Result<impl Into<Answer>> {
+        Ok(input.len())
+    }
+    fn part_two(input: &Self::Input<'_>) -> Result<impl Into<Answer>> {
+        Ok(input.concat())
+    }
+}
+
+#[aoc(day = 24, part = 1)]
+fn part_one_lines(input: &InputFile) -> Result<Answer> {
+    Ok(Answer::from(input.lines()?.count()))
+}
+
+fn run(day: u8, part: u8, input: &str) -> Result<Answer> {
+    let registration = registered()
+        .find(|r| r.day == day && r.part == part)
+        .expect("registered");
+    (registration.fun)(input)
+}
+
+#[test]
+fn raw_input() -> Result<()> {
+    assert_eq!(run(24, 1, "abc")?, Answer::Num(3));
+    Ok(())
+}
+#[test]
+fn parsed_input() -> Result<()> {
+    assert_eq!(run(24, 2, "1 2 39")?, Answer::Num(42));
+    let err = run(24, 2, "1 x").unwrap_err();
+    assert_eq!(err.to_string(), "not a number: x");
+    Ok(())
+}
+#[test]
+fn source_input() -> Result<()> {
+    let registration = registered_sources()
+        .find(|r| r.day == 24)
+        .expect("registered");
+    assert_eq!(registration.part, 1);
+    let lines = (registration.fun)(&InputFile::new("Cargo.toml"))?;
+    let expected = std::fs::read_to_string("Cargo.toml")?.lines().count();
+    assert_eq!(lines, Answer::from(expected));
+    Ok(())
+}
+#[test]
+fn converted_answer() -> Result<()> {
+    assert_eq!(run(23, 1, "a b c")?, Answer::Num(3));
+    Ok(())
+}
+#[test]
+fn solution() -> Result<()> {
+    let registration = registered_solutions()
+        .find(|r| r.day == 22)
+        .expect("registered");
+    let mut parts = (registration.fun)("ab\ncd");
+    assert_eq!(parts(1)?, Answer::Num(2));
+    assert_eq!(parts(2)?, Answer::from("abcd"));
+    Ok(())
+}
+#[test]
+fn only_marked_parts() {
+    assert_eq!(registered().filter(|r| r.day == 24).count(), 2);
+}
@@ -0,0 +1,20 @@
+use common::regx;
+
+fn numbers(line: &str) -> Vec<u32> {
+    regx!(r"\d+")
+        .find_iter(line)
+        .map(|m| m.as_str().parse().unwrap())
+        .collect()
+}
+
+#[test]
+fn cached_regex() {
+    assert_eq!(numbers("Card 1: 41 48 | 83"), [1, 41, 48, 83]);
+    let first = regx!(r"day\d{2}") as *const _;
+    let same = (0..2)
+        .map(|_| regx!(r"day\d{2}") as *const _)
+        .collect::<Vec<_>>();
+    // every call site has its own static
+    assert_ne!(first, same[0]);
+    assert_eq!(same[0], same[1]);
+}
@@ -0,0 +1,75 @@
+use std::{env, fmt::Write, fs, path::Path};
+
+// Generates the `pub mod dayNN;` declarations for every src/dayNN directory (public like
+// the days of the binary layouts, whose part_one and part_two are only used by tests),
+// main.rs includes them and the parts register themselves with
+// #[aoc(day = N, part = M)]. A day is compiled if the all-days feature or its own dayNN
+// feature is enabled (a new day only needs a feature to be built on its own). With
+// embed-inputs the *.txt files next to the days are compiled into the binary as well
+// (inputs.rs).
+fn main() {
+    let manifest_dir = env::var("CARGO_MANIFEST_DIR").unwrap();
+    let src = Path::new(&manifest_dir).join("src");
+    println!("cargo::rerun-if-changed=src");
+
+    let mut days: Vec<String> = fs::read_dir(&src)
+        .unwrap()
+        .map(|entry| entry.unwrap())
+        .filter(|entry| entry.path().join("mod.rs").is_file())
+        .map(|entry| entry.file_name().to_string_lossy().into_owned())
+        .filter(|name| {
+            name.len() == 5
+                && name.starts_with("day")
+                && name[3..].chars().all(|c| c.is_ascii_digit())
+        })
+        .collect();
+    days.sort();
+
+    let all_days = env::var_os("CARGO_FEATURE_ALL_DAYS").is_some();
+    let enabled = |day: &String| {
+        all_days || env::var_os(format!("CARGO_FEATURE_{}", day.to_uppercase())).is_some()
+    };
+    let days: Vec<&String> = days.iter().filter(|day| enabled(day)).collect();
+
+    let mut code = String::from("// @generated by build.rs from the dayNN directories in src/\n");
+    for day in days.iter() {
+        let path = src.join(day).join("mod.rs");
+        writeln!(
+            code,
+            "#[path = {:?}]\npub mod {};",
+            path.display().to_string(),
+            day
+        )
+        .unwrap();
+    }
+
+    // the input and example files of the compiled days with the embed-inputs feature
+    let mut inputs = String::from("// @generated by build.rs from the *.txt files in src/dayNN/\n");
+    inputs.push_str("static INPUTS: &[(u8, &str, &str)] = &[\n");
+    if env::var_os("CARGO_FEATURE_EMBED_INPUTS").is_some() {
+        for day in days.iter() {
+            let number: u8 = day[3..].parse().unwrap();
+            let mut files: Vec<_> = fs::read_dir(src.join(day))
+                .unwrap()
+                .map(|entry| entry.unwrap().path())
+                .filter(|path| path.extension().is_some_and(|extension| extension == "txt"))
+                .collect();
+            files.sort();
+            for file in files {
+                let name = file.file_name().unwrap().to_string_lossy();
+                let path = file.display().to_string();
+                writeln!(
+                    inputs,
+                    "    ({}, {:?}, include_str!({:?})),",
+                    number, name, path
+                )
+                .unwrap();
+            }
+        }
+    }
+    inputs.push_str("];\n");
+
+    let out_dir = env::var("OUT_DIR").unwrap();
+    fs::write(Path::new(&out_dir).join("days.rs"), code).unwrap();
+    fs::write(Path::new(&out_dir).join("inputs.rs"), inputs).unwrap();
+}
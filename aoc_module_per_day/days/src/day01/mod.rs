@@ -1,5 +1,8 @@
-use super::common::*;
-use anyhow::Result;
+// @generated by aoc_gen from solutions/day01/solution.rs, edit that file instead
+use common::anyhow::Result;
+use common::matcher::Matcher;
+use common::{aoc, aoc_parser, Answer};
+use std::sync::LazyLock;
 
 pub fn part_one(input: &str) -> Result<Answer> {
     let input = parse_input(input)?;
@@ -11,12 +14,13 @@ pub fn part_two(input: &str) -> Result<Answer> {
     solve_two(&input)
 }
 
-struct Input {
-    lines: Vec<String>,
+struct Input<'a> {
+    lines: Vec<&'a str>,
 }
 
-fn parse_input(input: &str) -> Result<Input> {
-    let lines: Vec<String> = input.lines().map(|l| String::from(l)).collect();
+#[aoc_parser]
+fn parse_input(input: &str) -> Result<Input<'_>> {
+    let lines: Vec<&str> = input.lines().collect();
     Ok(Input { lines })
 }
 
@@ -24,8 +28,8 @@ fn parse_line(line: &str) -> i128 {
     let mut first = None;
     let mut last = None;
     for c in line.chars() {
-        if c.is_digit(10) {
-            if first == None {
+        if c.is_ascii_digit() {
+            if first.is_none() {
                 first = Some(c);
             }
             last = Some(c);
@@ -38,29 +42,37 @@ fn parse_line(line: &str) -> i128 {
     ifd * 10 + ild
 }
 
-// doesn't work due to overlap
+// spelled digits may overlap, e.g. "twone" ends with a one
+static DIGITS: LazyLock<Matcher<i128>> = LazyLock::new(|| {
+    Matcher::with_values([
+        ("one", 1),
+        ("two", 2),
+        ("three", 3),
+        ("four", 4),
+        ("five", 5),
+        ("six", 6),
+        ("seven", 7),
+        ("eight", 8),
+        ("nine", 9),
+        ("1", 1),
+        ("2", 2),
+        ("3", 3),
+        ("4", 4),
+        ("5", 5),
+        ("6", 6),
+        ("7", 7),
+        ("8", 8),
+        ("9", 9),
+    ])
+});
+
 fn parse_advanced(line: &str) -> i128 {
-    let spelled_digits = vec![
-        ("one", "one1one"),
-        ("two", "two2two"),
-        ("three", "three3three"),
-        ("four", "four4four"),
-        ("five", "five5five"),
-        ("six", "six6six"),
-        ("seven", "seven7seven"),
-        ("eight", "eight8eight"),
-        ("nine", "nine9nine"),
-    ];
-    let mut line = String::from(line);
-    for (spelled, replacement) in spelled_digits {
-        line = line.replace(spelled, replacement);
-    }
-    // println!("{:?}", line);
-    let result = parse_line(&line);
-    // println!("{:?}", result);
-    result
+    let first = DIGITS.first(line).unwrap();
+    let last = DIGITS.last(line).unwrap();
+    first.value * 10 + last.value
 }
 
+#[aoc(day = 1, part = 1)]
 fn solve_one(input: &Input) -> Result<Answer> {
     let Input { lines } = input;
 
@@ -71,6 +83,7 @@ fn solve_one(input: &Input) -> Result<Answer> {
     Ok(Answer::Num(sum))
 }
 
+#[aoc(day = 1, part = 2)]
 fn solve_two(input: &Input) -> Result<Answer> {
     let Input { lines } = input;
 
@@ -84,7 +97,7 @@ fn solve_two(input: &Input) -> Result<Answer> {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::common::test_utils::*;
+    use common::test_utils::*;
     use std::sync::LazyLock;
     use test::Bencher;
 
@@ -119,10 +132,10 @@ mod tests {
 
     #[bench]
     fn bench_part_one(b: &mut Bencher) {
-        b.iter(|| part_one())
+        b.iter(part_one)
     }
     #[bench]
     fn bench_part_two(b: &mut Bencher) {
-        b.iter(|| part_two())
+        b.iter(part_two)
     }
 }
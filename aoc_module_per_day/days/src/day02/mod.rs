@@ -1,19 +1,23 @@
-use crate::common::Answer;
-use anyhow::{anyhow, Result};
+// @generated by aoc_gen from solutions/day02/solution.rs, edit that file instead
+use common::anyhow::{anyhow, Error, Result};
+use common::{aoc, Answer, FromLine, Solution};
+use std::str::FromStr;
 
 pub fn part_one(input: &str) -> Result<Answer> {
-    let input = parse_input(input)?;
-    solve_one(&input)
+    Ok(Day02::part_one(&Day02::parse(input)?)?.into())
 }
 
 pub fn part_two(input: &str) -> Result<Answer> {
-    let input = parse_input(input)?;
-    solve_two(&input)
+    Ok(Day02::part_two(&Day02::parse(input)?)?.into())
 }
 
-#[derive(Debug)]
+struct Day02;
+
+#[derive(Debug, FromLine)]
+#[line(regex = r"^Game (?P<id>\d+): (?P<sets>.*)$")]
 struct Game {
     id: i128,
+    #[line(sep = ";")]
     sets: Vec<GameSet>,
 }
 
@@ -24,6 +28,13 @@ struct GameSet {
     blue: i128,
 }
 
+#[derive(FromLine)]
+#[line("{} {}")]
+struct Cubes {
+    amount: i128,
+    color: String,
+}
+
 #[derive(Debug)]
 struct Input {
     games: Vec<Game>,
@@ -62,66 +73,59 @@ impl GameSet {
     }
 }
 
-fn parse_input(input: &str) -> Result<Input> {
-    let games = input
-        .lines()
-        .map(|l| parse_game(l))
-        .collect::<Result<Vec<Game>>>()?;
-    Ok(Input { games })
+impl FromStr for GameSet {
+    type Err = Error;
+    fn from_str(set: &str) -> Result<GameSet> {
+        let mut red = 0;
+        let mut green = 0;
+        let mut blue = 0;
+        for cubes in set.split(",") {
+            let Cubes { amount, color } = cubes.trim().parse()?;
+            match color.as_str() {
+                "red" => red += amount,
+                "green" => green += amount,
+                "blue" => blue += amount,
+                _ => return Err(anyhow!("unknown color {:?} in {:?}", color, set)),
+            }
+        }
+        Ok(GameSet { red, green, blue })
+    }
 }
 
-fn parse_game(line: &str) -> Result<Game> {
-    // println!("{:?}", line);
-    let v: Vec<&str> = line.split(":").map(|s| s.trim()).collect();
-    // println!("{:?}", v);
-    let id = v[0]
-        .split(" ")
-        .nth(1)
-        .ok_or(anyhow!("nth(1) was None"))?
-        .parse::<i128>()?;
-    let sets = v[1];
-    let s: Vec<&str> = sets.split(";").map(|s| s.trim()).collect();
-    let sets: Vec<GameSet> = s
-        .into_iter()
-        .map(|s| parse_set(s))
-        .collect::<Result<Vec<GameSet>>>()?;
-    Ok(Game { id, sets })
-}
+#[aoc(day = 2)]
+impl Solution for Day02 {
+    type Input<'a> = Input;
 
-fn parse_set(set: &str) -> Result<GameSet> {
-    let mut red = 0;
-    let mut green = 0;
-    let mut blue = 0;
-    let components: Vec<&str> = set.split(",").map(|s| s.trim()).collect();
-    for c in components {
-        let v: Vec<&str> = c.split(" ").collect();
-        let amount = v[0].parse::<i128>()?;
-        match v[1] {
-            "red" => red += amount,
-            "green" => green += amount,
-            "blue" => blue += amount,
-            _ => panic!("parse_set error"),
-        }
+    fn parse(input: &str) -> Result<Input> {
+        let games = input
+            .lines()
+            .map(str::parse)
+            .collect::<Result<Vec<Game>>>()?;
+        Ok(Input { games })
     }
-    Ok(GameSet { red, green, blue })
-}
 
-fn solve_one(input: &Input) -> Result<Answer> {
-    let Input { games } = input;
-    let sum = games.iter().filter(|g| g.is_valid()).map(|g| g.id).sum();
-    Ok(Answer::Num(sum))
-}
+    fn part_one(input: &Self::Input<'_>) -> Result<impl Into<Answer>> {
+        let Input { games } = input;
+        Ok(games
+            .iter()
+            .filter(|g| g.is_valid())
+            .map(|g| g.id)
+            .sum::<i128>())
+    }
 
-fn solve_two(input: &Input) -> Result<Answer> {
-    let Input { games } = input;
-    let sum = games.iter().map(|g| g.fewest_possible().power()).sum();
-    Ok(Answer::Num(sum))
+    fn part_two(input: &Self::Input<'_>) -> Result<impl Into<Answer>> {
+        let Input { games } = input;
+        Ok(games
+            .iter()
+            .map(|g| g.fewest_possible().power())
+            .sum::<i128>())
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::common::test_utils::*;
+    use common::test_utils::*;
     use std::sync::LazyLock;
     use test::Bencher;
 
@@ -155,10 +159,10 @@ mod tests {
 
     #[bench]
     fn bench_part_one(b: &mut Bencher) {
-        b.iter(|| part_one())
+        b.iter(part_one)
     }
     #[bench]
     fn bench_part_two(b: &mut Bencher) {
-        b.iter(|| part_two())
+        b.iter(part_two)
     }
 }
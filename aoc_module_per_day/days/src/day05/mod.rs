@@ -1,5 +1,7 @@
-use crate::common::{regx, Answer};
-use anyhow::Result;
+// @generated by aoc_gen from solutions/day05/solution.rs, edit that file instead
+use common::anyhow::{anyhow, Result};
+use common::input::InputFile;
+use common::{aoc, aoc_parser, assume, parse, trace, Answer};
 use std::collections::{HashSet, VecDeque};
 
 pub fn part_one(input: &str) -> Result<Answer> {
@@ -50,6 +52,7 @@ impl Input {
         current_ranges.iter().map(|&(a, _)| a).min().unwrap()
     }
     fn multi_mappings(&self, seed_range: (usize, usize), mapping: &Mapping) -> Vec<(usize, usize)> {
+        let _span = trace::span("multi_mappings");
         let (_start, len) = seed_range;
         let mut result: HashSet<(usize, usize)> = HashSet::new();
         let mut not_mapped = VecDeque::new();
@@ -81,7 +84,13 @@ impl Input {
         // println!("{:?}", seed_range);
         // println!("{:?}", result);
         let result: Vec<(usize, usize)> = result.into_iter().collect();
-        assert_eq!(len, result.iter().map(|(_, l)| l).sum());
+        assume!(
+            len == result.iter().map(|(_, l)| l).sum::<usize>(),
+            "the ranges of a map don't overlap",
+            "{:?} became {:?}",
+            seed_range,
+            result
+        );
         result
     }
     fn map_range_tuple(&self, rt: (usize, usize), mapping: &Mapping) -> (usize, usize) {
@@ -102,6 +111,7 @@ impl Input {
         }
     }
     fn intersect_ranges(&self, r1: (usize, usize), r2: (usize, usize)) -> Option<(usize, usize)> {
+        let _span = trace::span("intersect_ranges");
         let (mut r1_start, mut r1_len) = r1;
         let (mut r2_start, mut r2_len) = r2;
         if r1_start > r2_start {
@@ -146,38 +156,39 @@ struct Mapping {
 
 #[derive(Debug)]
 struct RangeMapping {
-    src: usize,
     dst: usize,
+    src: usize,
     len: usize,
 }
 
+#[aoc_parser]
 fn parse_input(input: &str) -> Result<Input> {
-    let blocks: Vec<&str> = input.split("\r\n\r\n").collect();
-    // println!("{:?}", foo);
-    let num_re = regx!(r"\d+");
-    let initial_seeds = num_re
-        .find_iter(blocks[0])
-        .map(|m| m.as_str().parse().unwrap())
-        .collect();
+    // blocks handles "\r\n" without a copy of the input, which may be a huge mapped file
+    let mut blocks = parse::blocks(input);
+    let seeds = blocks.next().unwrap_or_default();
+    if !seeds.starts_with(b"seeds:") {
+        return Err(anyhow!("the almanac doesn't start with the seeds"));
+    }
+    let initial_seeds = parse::try_integers(seeds).collect::<Result<_>>()?;
     let mut mappings = Vec::new();
-    for i in 1..blocks.len() {
-        let mut range_mappings = Vec::new();
-        let lines = blocks[i].lines();
-        for line in lines.skip(1) {
-            let range_mapping: Vec<usize> = num_re
-                .find_iter(line)
-                .map(|m| m.as_str().parse().unwrap())
-                .collect();
-
-            range_mappings.push(RangeMapping {
-                src: range_mapping[1],
-                dst: range_mapping[0],
-                len: range_mapping[2],
-            });
+    for block in blocks {
+        // the header ("seed-to-soil map:") has no digits, the lines after it three numbers each
+        let numbers: Vec<usize> = parse::try_integers(block).collect::<Result<_>>()?;
+        if !numbers.len().is_multiple_of(3) {
+            return Err(anyhow!(
+                "a map line doesn't have three numbers in {:?}",
+                String::from_utf8_lossy(block)
+            ));
         }
-        mappings.push(Mapping {
-            ranges: range_mappings,
-        });
+        let ranges = numbers
+            .chunks_exact(3)
+            .map(|range| RangeMapping {
+                dst: range[0],
+                src: range[1],
+                len: range[2],
+            })
+            .collect();
+        mappings.push(Mapping { ranges });
     }
     Ok(Input {
         initial_seeds,
@@ -185,20 +196,46 @@ fn parse_input(input: &str) -> Result<Input> {
     })
 }
 
+#[aoc(day = 5, part = 1)]
 fn solve_one(input: &Input) -> Result<Answer> {
     // println!("{:?}", input.initial_seeds);
     // println!("{:?}", input.mappings);
     Ok(Answer::Num(input.find_lowest() as i128))
 }
 
+#[aoc(day = 5, part = 2)]
 fn solve_two(input: &Input) -> Result<Answer> {
     Ok(Answer::Num(input.find_lowest_ranges() as i128))
 }
 
+// The Solver runners map the input file instead of reading it into a String, for
+// generated almanacs of a few gigabytes. Only the module per day and workspace layouts
+// get these, see aoc_gen.
+#[aoc(day = 5, part = 1)]
+fn solve_one_mapped(input: &InputFile) -> Result<Answer> {
+    solve_mapped(input, solve_one)
+}
+
+#[aoc(day = 5, part = 2)]
+fn solve_two_mapped(input: &InputFile) -> Result<Answer> {
+    solve_mapped(input, solve_two)
+}
+
+// the macro only sees the whole part, so the spans of the parsed parts are recorded here
+fn solve_mapped(input: &InputFile, solve: fn(&Input) -> Result<Answer>) -> Result<Answer> {
+    let bytes = input.bytes()?;
+    let parsed = {
+        let _span = trace::span("parse");
+        parse_input(std::str::from_utf8(&bytes)?)?
+    };
+    let _span = trace::span("solve");
+    solve(&parsed)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::common::test_utils::*;
+    use common::test_utils::*;
     use std::sync::LazyLock;
     use test::Bencher;
 
@@ -229,14 +266,31 @@ mod tests {
         assert_eq!(answer, Answer::Num(34039469));
         Ok(())
     }
+    #[test]
+    fn too_large() {
+        let err = parse_input("seeds: 99999999999999999999\n").err().unwrap();
+        assert_eq!(
+            err.to_string(),
+            "99999999999999999999 doesn't fit into usize"
+        );
+    }
+
+    #[test]
+    fn mapped() -> Result<()> {
+        let path = local_path(env!("CARGO_MANIFEST_DIR"), file!(), "input.txt");
+        let input = InputFile::new(path);
+        assert_eq!(solve_one_mapped(&input)?, Answer::Num(26273516));
+        assert_eq!(solve_two_mapped(&input)?, Answer::Num(34039469));
+        Ok(())
+    }
 
     #[bench]
     fn bench_part_one(b: &mut Bencher) {
-        b.iter(|| part_one())
+        b.iter(part_one)
     }
     #[bench]
     fn bench_part_two(b: &mut Bencher) {
-        b.iter(|| part_two())
+        b.iter(part_two)
     }
     #[bench]
     fn bench_solve_two(b: &mut Bencher) {
@@ -1,38 +1,21 @@
 #![feature(test)]
 extern crate test;
 
-mod common;
-mod day01;
-mod day02;
-mod day03;
-mod day04;
-mod day05;
-mod solver;
+use common::solver::{Args, Solver, StructOpt};
+use std::process::ExitCode;
 
-use solver::Solver;
-use std::path::PathBuf;
-use structopt::StructOpt;
+// mod dayNN for every src/dayNN directory, see build.rs
+include!(concat!(env!("OUT_DIR"), "/days.rs"));
+// their input and example files with the embed-inputs feature, empty otherwise
+include!(concat!(env!("OUT_DIR"), "/inputs.rs"));
 
-#[derive(Debug, StructOpt)]
-enum Opt {
-    All,
-    Day { day: u8, part: Option<u8> },
-    File { day: u8, part: u8, file: PathBuf },
-}
-
-fn main() {
-    let opt = Opt::from_args();
+fn main() -> ExitCode {
+    let args = Args::from_args();
 
     let mut solver = Solver::new();
-    solver.add(1, 1, day01::part_one);
-    solver.add(1, 2, day01::part_two);
-    solver.add(2, 1, day02::part_one);
-    solver.add(2, 2, day02::part_two);
-    solver.add(3, 1, day03::part_one);
-    solver.add(3, 2, day03::part_two);
-    solver.add(4, 1, day04::part_one);
-    solver.add(4, 2, day04::part_two);
-    solver.add(5, 1, day05::part_one);
-    solver.add(5, 2, day05::part_two);
-    solver.solve(opt);
+    solver.add_registered();
+    for &(day, name, text) in INPUTS {
+        solver.embed(day, name, text);
+    }
+    solver.run(args)
 }
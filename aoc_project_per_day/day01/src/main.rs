@@ -1,15 +1,14 @@
+// @generated by aoc_gen from solutions/day01/solution.rs, edit that file instead
 #![feature(test)]
 extern crate test;
 
 use common::anyhow::Result;
+use common::matcher::Matcher;
 use common::Answer;
-use std::io;
+use std::sync::LazyLock;
 
-pub fn main() -> Result<()> {
-    let stdin = io::read_to_string(io::stdin())?;
-    println!("part1: {}", part_one(&stdin)?);
-    println!("part2: {}", part_two(&stdin)?);
-    Ok(())
+pub fn main() -> std::process::ExitCode {
+    common::cli::main(1, parse_input, solve_one, solve_two)
 }
 
 pub fn part_one(input: &str) -> Result<Answer> {
@@ -22,12 +21,12 @@ pub fn part_two(input: &str) -> Result<Answer> {
     solve_two(&input)
 }
 
-struct Input {
-    lines: Vec<String>,
+struct Input<'a> {
+    lines: Vec<&'a str>,
 }
 
-fn parse_input(input: &str) -> Result<Input> {
-    let lines: Vec<String> = input.lines().map(|l| String::from(l)).collect();
+fn parse_input(input: &str) -> Result<Input<'_>> {
+    let lines: Vec<&str> = input.lines().collect();
     Ok(Input { lines })
 }
 
@@ -35,8 +34,8 @@ fn parse_line(line: &str) -> i128 {
     let mut first = None;
     let mut last = None;
     for c in line.chars() {
-        if c.is_digit(10) {
-            if first == None {
+        if c.is_ascii_digit() {
+            if first.is_none() {
                 first = Some(c);
             }
             last = Some(c);
@@ -49,27 +48,34 @@ fn parse_line(line: &str) -> i128 {
     ifd * 10 + ild
 }
 
-// doesn't work due to overlap
+// spelled digits may overlap, e.g. "twone" ends with a one
+static DIGITS: LazyLock<Matcher<i128>> = LazyLock::new(|| {
+    Matcher::with_values([
+        ("one", 1),
+        ("two", 2),
+        ("three", 3),
+        ("four", 4),
+        ("five", 5),
+        ("six", 6),
+        ("seven", 7),
+        ("eight", 8),
+        ("nine", 9),
+        ("1", 1),
+        ("2", 2),
+        ("3", 3),
+        ("4", 4),
+        ("5", 5),
+        ("6", 6),
+        ("7", 7),
+        ("8", 8),
+        ("9", 9),
+    ])
+});
+
 fn parse_advanced(line: &str) -> i128 {
-    let spelled_digits = vec![
-        ("one", "one1one"),
-        ("two", "two2two"),
-        ("three", "three3three"),
-        ("four", "four4four"),
-        ("five", "five5five"),
-        ("six", "six6six"),
-        ("seven", "seven7seven"),
-        ("eight", "eight8eight"),
-        ("nine", "nine9nine"),
-    ];
-    let mut line = String::from(line);
-    for (spelled, replacement) in spelled_digits {
-        line = line.replace(spelled, replacement);
-    }
-    // println!("{:?}", line);
-    let result = parse_line(&line);
-    // println!("{:?}", result);
-    result
+    let first = DIGITS.first(line).unwrap();
+    let last = DIGITS.last(line).unwrap();
+    first.value * 10 + last.value
 }
 
 fn solve_one(input: &Input) -> Result<Answer> {
@@ -130,10 +136,10 @@ mod tests {
 
     #[bench]
     fn bench_part_one(b: &mut Bencher) {
-        b.iter(|| part_one())
+        b.iter(part_one)
     }
     #[bench]
     fn bench_part_two(b: &mut Bencher) {
-        b.iter(|| part_two())
+        b.iter(part_two)
     }
 }
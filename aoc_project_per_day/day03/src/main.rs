@@ -1,17 +1,14 @@
+// @generated by aoc_gen from solutions/day03/solution.rs, edit that file instead
 #![feature(test)]
 extern crate test;
 
 use common::anyhow::Result;
 
-use common::{regx, Answer};
+use common::{assume, parse, Answer};
 use std::collections::{HashMap, HashSet};
-use std::io;
 
-pub fn main() -> Result<()> {
-    let stdin = io::read_to_string(io::stdin())?;
-    println!("part1: {}", part_one(&stdin)?);
-    println!("part2: {}", part_two(&stdin)?);
-    Ok(())
+pub fn main() -> std::process::ExitCode {
+    common::cli::main(3, parse_input, solve_one, solve_two)
 }
 
 pub fn part_one(input: &str) -> Result<Answer> {
@@ -25,8 +22,8 @@ pub fn part_two(input: &str) -> Result<Answer> {
 }
 
 #[derive(Debug)]
-struct Input {
-    lines: Vec<String>,
+struct Input<'a> {
+    lines: Vec<&'a str>,
     numbers: Vec<Vec<Number>>,
     gears: Vec<(usize, usize)>,
 }
@@ -38,15 +35,15 @@ struct Number {
     end: usize,
 }
 
-impl Input {
+impl Input<'_> {
     fn sum_adjacent(&self) -> i128 {
         let grid = self
             .lines
             .iter()
             .map(|l| l.chars().collect())
             .collect::<Vec<Vec<_>>>();
-        let dx = vec![-1, 0, 1, -1, 1, -1, 0, 1];
-        let dy = vec![-1, -1, -1, 0, 0, 1, 1, 1];
+        let dx = [-1, 0, 1, -1, 1, -1, 0, 1];
+        let dy = [-1, -1, -1, 0, 0, 1, 1, 1];
 
         let mut sum = 0;
         for (y, numbers) in self.numbers.iter().enumerate() {
@@ -85,8 +82,8 @@ impl Input {
             .iter()
             .map(|l| l.chars().collect())
             .collect::<Vec<Vec<_>>>();
-        let dx = vec![-1, 0, 1, -1, 1, -1, 0, 1];
-        let dy = vec![-1, -1, -1, 0, 0, 1, 1, 1];
+        let dx = [-1, 0, 1, -1, 1, -1, 0, 1];
+        let dy = [-1, -1, -1, 0, 0, 1, 1, 1];
 
         for (y, numbers) in self.numbers.iter().enumerate() {
             for num in numbers {
@@ -118,8 +115,15 @@ impl Input {
         }
         gear_map
             .iter()
-            .map(|(_, nums)| {
-                // assert_eq!(nums.len(), 2);
+            .map(|(gear, nums)| {
+                // a * next to a single number is no gear, three would be ambiguous
+                assume!(
+                    nums.len() <= 2,
+                    "a * touches at most two numbers",
+                    "the * at {:?} touches {:?}",
+                    gear,
+                    nums
+                );
                 if nums.len() != 2 {
                     0
                 } else {
@@ -134,29 +138,28 @@ impl Input {
     }
 }
 
-fn parse_input(input: &str) -> Result<Input> {
+fn parse_input(input: &str) -> Result<Input<'_>> {
     // let grid = input
     //     .lines()
     //     .map(|l| l.chars().collect::<Vec<_>>())
     //     .collect::<Vec<_>>();
-    let lines: Vec<String> = input.lines().map(|s| String::from(s)).collect();
-
-    let number_regex = regx!(r"\d+");
+    let lines: Vec<&str> = input.lines().collect();
 
     let mut numbers = Vec::new(); // TODO with_capacity?
     let mut gears = Vec::new();
     for (line_number, line) in lines.iter().enumerate() {
-        let numbers_in_line: Vec<Number> = number_regex
-            .find_iter(line)
-            .map(|m| Number {
-                val: m
-                    .as_str()
-                    .parse::<i128>()
-                    .expect("parse i128 was not possible with found regex"),
-                start: m.start(),
-                end: m.end(),
+        // unsigned, a '-' is a symbol
+        let numbers_in_line: Vec<Number> = parse::try_integers::<u64>(line)
+            .spanned()
+            .map(|number| {
+                let (val, span) = number?;
+                Ok(Number {
+                    val: val as i128,
+                    start: span.start,
+                    end: span.end,
+                })
             })
-            .collect();
+            .collect::<Result<_>>()?;
         numbers.push(numbers_in_line);
         let mut g: Vec<(usize, usize)> = line
             .match_indices("*")
@@ -218,10 +221,10 @@ mod tests {
 
     #[bench]
     fn bench_part_one(b: &mut Bencher) {
-        b.iter(|| part_one())
+        b.iter(part_one)
     }
     #[bench]
     fn bench_part_two(b: &mut Bencher) {
-        b.iter(|| part_two())
+        b.iter(part_two)
     }
 }
@@ -1,16 +1,13 @@
+// @generated by aoc_gen from solutions/day04/solution.rs, edit that file instead
 #![feature(test)]
 extern crate test;
 
-use common::anyhow::Result;
-use common::Answer;
+use common::anyhow::{anyhow, Result};
+use common::{parse, Answer};
 use std::collections::{HashSet, VecDeque};
-use std::io;
 
-pub fn main() -> Result<()> {
-    let stdin = io::read_to_string(io::stdin())?;
-    println!("part1: {}", part_one(&stdin)?);
-    println!("part2: {}", part_two(&stdin)?);
-    Ok(())
+pub fn main() -> std::process::ExitCode {
+    common::cli::main(4, parse_input, solve_one, solve_two)
 }
 
 pub fn part_one(input: &str) -> Result<Answer> {
@@ -34,36 +31,33 @@ struct Card {
 }
 
 fn parse_input(input: &str) -> Result<Input> {
-    let lines = input.lines();
-    let mut cards = Vec::new();
-    for line in lines {
-        let (card, rest) = line.split_once(":").unwrap();
-        let id = card.split_whitespace().collect::<Vec<_>>()[1]
-            .parse::<usize>()
-            .unwrap();
-        let (winners, numbers) = rest.trim().split_once("|").unwrap();
-        let winners = winners
-            .split_whitespace()
-            .map(|s| s.parse::<usize>().unwrap())
-            .collect::<HashSet<_>>();
-        let numbers = numbers
-            .split_whitespace()
-            .map(|s| s.parse::<usize>().unwrap())
-            .collect::<Vec<_>>();
-        let mut matching = 0;
-        for num in numbers.iter() {
-            if winners.contains(&num) {
-                matching += 1;
-            }
+    let cards = input.lines().map(parse_card).collect::<Result<_>>()?;
+    Ok(Input { cards })
+}
+
+// "Card 1: 41 48 83 | 83 86  6", the id is the first number before the |
+fn parse_card(line: &str) -> Result<Card> {
+    let (card, numbers) = line
+        .split_once('|')
+        .ok_or_else(|| anyhow!("no | in {:?}", line))?;
+    let mut card = parse::try_integers(card);
+    let id = card
+        .next()
+        .ok_or_else(|| anyhow!("no card id in {:?}", line))??;
+    let winners = card.collect::<Result<HashSet<_>>>()?;
+    let numbers = parse::try_integers(numbers).collect::<Result<Vec<_>>>()?;
+    let mut matching = 0;
+    for num in numbers.iter() {
+        if winners.contains(num) {
+            matching += 1;
         }
-        cards.push(Card {
-            id,
-            _winners: winners,
-            _numbers: numbers,
-            matching,
-        });
     }
-    Ok(Input { cards })
+    Ok(Card {
+        id,
+        _winners: winners,
+        _numbers: numbers,
+        matching,
+    })
 }
 
 fn solve_one(input: &Input) -> Result<Answer> {
@@ -72,7 +66,6 @@ fn solve_one(input: &Input) -> Result<Answer> {
     for card in cards {
         if card.matching > 0 {
             let card_worth = 2_i32.pow(card.matching as u32 - 1);
-            println!("{}", card_worth);
             total += card_worth
         }
     }
@@ -142,10 +135,10 @@ mod tests {
 
     #[bench]
     fn bench_part_one(b: &mut Bencher) {
-        b.iter(|| part_one())
+        b.iter(part_one)
     }
     #[bench]
     fn bench_part_two(b: &mut Bencher) {
-        b.iter(|| part_two())
+        b.iter(part_two)
     }
 }
@@ -1,16 +1,13 @@
+// @generated by aoc_gen from solutions/day05/solution.rs, edit that file instead
 #![feature(test)]
 extern crate test;
 
-use common::anyhow::Result;
-use common::{regx, Answer};
+use common::anyhow::{anyhow, Result};
+use common::{assume, parse, trace, Answer};
 use std::collections::{HashSet, VecDeque};
-use std::io;
 
-pub fn main() -> Result<()> {
-    let stdin = io::read_to_string(io::stdin())?;
-    println!("part1: {}", part_one(&stdin)?);
-    println!("part2: {}", part_two(&stdin)?);
-    Ok(())
+pub fn main() -> std::process::ExitCode {
+    common::cli::main(5, parse_input, solve_one, solve_two)
 }
 
 pub fn part_one(input: &str) -> Result<Answer> {
@@ -61,6 +58,7 @@ impl Input {
         current_ranges.iter().map(|&(a, _)| a).min().unwrap()
     }
     fn multi_mappings(&self, seed_range: (usize, usize), mapping: &Mapping) -> Vec<(usize, usize)> {
+        let _span = trace::span("multi_mappings");
         let (_start, len) = seed_range;
         let mut result: HashSet<(usize, usize)> = HashSet::new();
         let mut not_mapped = VecDeque::new();
@@ -92,7 +90,13 @@ impl Input {
         // println!("{:?}", seed_range);
         // println!("{:?}", result);
         let result: Vec<(usize, usize)> = result.into_iter().collect();
-        assert_eq!(len, result.iter().map(|(_, l)| l).sum());
+        assume!(
+            len == result.iter().map(|(_, l)| l).sum::<usize>(),
+            "the ranges of a map don't overlap",
+            "{:?} became {:?}",
+            seed_range,
+            result
+        );
         result
     }
     fn map_range_tuple(&self, rt: (usize, usize), mapping: &Mapping) -> (usize, usize) {
@@ -113,6 +117,7 @@ impl Input {
         }
     }
     fn intersect_ranges(&self, r1: (usize, usize), r2: (usize, usize)) -> Option<(usize, usize)> {
+        let _span = trace::span("intersect_ranges");
         let (mut r1_start, mut r1_len) = r1;
         let (mut r2_start, mut r2_len) = r2;
         if r1_start > r2_start {
@@ -157,38 +162,38 @@ struct Mapping {
 
 #[derive(Debug)]
 struct RangeMapping {
-    src: usize,
     dst: usize,
+    src: usize,
     len: usize,
 }
 
 fn parse_input(input: &str) -> Result<Input> {
-    let blocks: Vec<&str> = input.split("\r\n\r\n").collect();
-    // println!("{:?}", foo);
-    let num_re = regx!(r"\d+");
-    let initial_seeds = num_re
-        .find_iter(blocks[0])
-        .map(|m| m.as_str().parse().unwrap())
-        .collect();
+    // blocks handles "\r\n" without a copy of the input, which may be a huge mapped file
+    let mut blocks = parse::blocks(input);
+    let seeds = blocks.next().unwrap_or_default();
+    if !seeds.starts_with(b"seeds:") {
+        return Err(anyhow!("the almanac doesn't start with the seeds"));
+    }
+    let initial_seeds = parse::try_integers(seeds).collect::<Result<_>>()?;
     let mut mappings = Vec::new();
-    for i in 1..blocks.len() {
-        let mut range_mappings = Vec::new();
-        let lines = blocks[i].lines();
-        for line in lines.skip(1) {
-            let range_mapping: Vec<usize> = num_re
-                .find_iter(line)
-                .map(|m| m.as_str().parse().unwrap())
-                .collect();
-
-            range_mappings.push(RangeMapping {
-                src: range_mapping[1],
-                dst: range_mapping[0],
-                len: range_mapping[2],
-            });
+    for block in blocks {
+        // the header ("seed-to-soil map:") has no digits, the lines after it three numbers each
+        let numbers: Vec<usize> = parse::try_integers(block).collect::<Result<_>>()?;
+        if !numbers.len().is_multiple_of(3) {
+            return Err(anyhow!(
+                "a map line doesn't have three numbers in {:?}",
+                String::from_utf8_lossy(block)
+            ));
         }
-        mappings.push(Mapping {
-            ranges: range_mappings,
-        });
+        let ranges = numbers
+            .chunks_exact(3)
+            .map(|range| RangeMapping {
+                dst: range[0],
+                src: range[1],
+                len: range[2],
+            })
+            .collect();
+        mappings.push(Mapping { ranges });
     }
     Ok(Input {
         initial_seeds,
@@ -240,14 +245,22 @@ mod tests {
         assert_eq!(answer, Answer::Num(34039469));
         Ok(())
     }
+    #[test]
+    fn too_large() {
+        let err = parse_input("seeds: 99999999999999999999\n").err().unwrap();
+        assert_eq!(
+            err.to_string(),
+            "99999999999999999999 doesn't fit into usize"
+        );
+    }
 
     #[bench]
     fn bench_part_one(b: &mut Bencher) {
-        b.iter(|| part_one())
+        b.iter(part_one)
     }
     #[bench]
     fn bench_part_two(b: &mut Bencher) {
-        b.iter(|| part_two())
+        b.iter(part_two)
     }
     #[bench]
     fn bench_solve_two(b: &mut Bencher) {
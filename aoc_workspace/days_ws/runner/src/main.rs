@@ -0,0 +1,22 @@
+use common::solver::{Args, Solver, StructOpt};
+use std::{path::Path, process::ExitCode};
+
+// the days register their parts with #[aoc] (see Solver::add_registered), they only need
+// to be linked
+use day01 as _;
+use day02 as _;
+use day03 as _;
+use day04 as _;
+use day05 as _;
+
+// The overarching program of the workspace: every day is its own crate (so changing a
+// day only recompiles that day and this runner), the runner uses them as libraries.
+fn main() -> ExitCode {
+    let args = Args::from_args();
+
+    let mut solver = Solver::with_input_file(|day| {
+        Path::new(env!("CARGO_MANIFEST_DIR")).join(format!("../day{:02}/src/input.txt", day))
+    });
+    solver.add_registered();
+    solver.run(args)
+}
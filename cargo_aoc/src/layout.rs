@@ -0,0 +1,248 @@
+use std::{
+    env, fs,
+    path::{Path, PathBuf},
+    process::Command,
+};
+
+// The four layouts of this repository, told apart by where their days live.
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Kind {
+    // src/dayNN/mod.rs next to the Cargo.toml
+    ModulePerDay,
+    // dayNN/Cargo.toml members of a workspace with a runner
+    Workspace,
+    // dayNN/Cargo.toml packages in a directory without Cargo.toml
+    ProjectPerDay,
+    // src/bin/dayNN/main.rs next to the Cargo.toml
+    BinPerDay,
+}
+
+#[derive(Debug)]
+pub struct Layout {
+    pub kind: Kind,
+    pub root: PathBuf,
+}
+
+pub enum Action {
+    Run(Option<u8>),
+    Test,
+    Bench,
+}
+
+impl Layout {
+    // the nearest directory at or above start containing one of the layouts
+    pub fn find(start: &Path) -> Option<Layout> {
+        start.ancestors().find_map(Layout::at)
+    }
+    fn at(dir: &Path) -> Option<Layout> {
+        let manifest = fs::read_to_string(dir.join("Cargo.toml")).ok();
+        let kind = match manifest {
+            Some(_) if !day_dirs(&dir.join("src"), "mod.rs").is_empty() => Kind::ModulePerDay,
+            Some(_) if !day_dirs(&dir.join("src/bin"), "main.rs").is_empty() => Kind::BinPerDay,
+            Some(manifest)
+                if manifest.contains("[workspace]") && !day_dirs(dir, "Cargo.toml").is_empty() =>
+            {
+                Kind::Workspace
+            }
+            None if !day_dirs(dir, "Cargo.toml").is_empty() => Kind::ProjectPerDay,
+            _ => return None,
+        };
+        Some(Layout {
+            kind,
+            root: dir.to_path_buf(),
+        })
+    }
+
+    // the cargo invocations for the action, with a label to print before each
+    pub fn commands(
+        &self,
+        action: Action,
+        day: Option<u8>,
+    ) -> Result<Vec<(Option<String>, Command)>, String> {
+        // the runners only take a part after a day
+        if let (Action::Run(Some(part)), None) = (&action, day) {
+            return Err(format!(
+                "part {} needs a day, e.g. `cargo aoc run 5 {}`",
+                part, part
+            ));
+        }
+        let name = day.map(|day| format!("day{:02}", day));
+        if self.kind == Kind::ProjectPerDay {
+            let mut commands = Vec::new();
+            for (dir_day, dir) in day_dirs(&self.root, "Cargo.toml") {
+                if day.is_some_and(|day| day != dir_day) {
+                    continue;
+                }
+                let mut command = cargo(&dir);
+                match action {
+                    Action::Run(part) => {
+                        command.args(["run", "--", "--input", "src/input.txt"]);
+                        if let Some(part) = part {
+                            command.args(["--part", &part.to_string()]);
+                        }
+                    }
+                    Action::Test => _ = command.arg("test"),
+                    Action::Bench => _ = command.arg("bench"),
+                }
+                commands.push((Some(format!("day{:02}", dir_day)), command));
+            }
+            if commands.is_empty() {
+                return Err(format!("no {} in {}", name.unwrap(), self.root.display()));
+            }
+            return Ok(commands);
+        }
+        let mut command = cargo(&self.root);
+        match action {
+            Action::Run(part) => {
+                command.arg("run");
+                if self.kind == Kind::Workspace {
+                    command.args(["-p", "runner"]);
+                }
+                command.arg("--");
+                match day {
+                    Some(day) => command.args(["day", &day.to_string()]),
+                    None => command.arg("all"),
+                };
+                if let Some(part) = part {
+                    command.arg(part.to_string());
+                }
+            }
+            Action::Test | Action::Bench => {
+                command.arg(if let Action::Test = action {
+                    "test"
+                } else {
+                    "bench"
+                });
+                match (&self.kind, name) {
+                    (Kind::ModulePerDay, Some(name)) => _ = command.arg(format!("{}::", name)),
+                    (Kind::BinPerDay, Some(name)) => _ = command.args(["--bin", &name]),
+                    (Kind::Workspace, Some(name)) => _ = command.args(["-p", &name]),
+                    (Kind::Workspace, None) => _ = command.arg("--workspace"),
+                    _ => {}
+                }
+            }
+        }
+        Ok(vec![(None, command)])
+    }
+}
+
+// cargo sets $CARGO for its subcommands, so the same toolchain is used
+fn cargo(dir: &Path) -> Command {
+    let mut command = Command::new(env::var("CARGO").unwrap_or(String::from("cargo")));
+    command.current_dir(dir);
+    command
+}
+
+// the dayNN directories below dir containing file, sorted by day
+pub fn day_dirs(dir: &Path, file: &str) -> Vec<(u8, PathBuf)> {
+    let Ok(entries) = fs::read_dir(dir) else {
+        return Vec::new();
+    };
+    let mut days: Vec<(u8, PathBuf)> = entries
+        .filter_map(|entry| {
+            let path = entry.ok()?.path();
+            let name = path.file_name()?.to_str()?;
+            let number = name.strip_prefix("day")?;
+            if number.len() != 2 || !path.join(file).is_file() {
+                return None;
+            }
+            Some((number.parse().ok()?, path))
+        })
+        .collect();
+    days.sort();
+    days
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn find(dir: &str) -> Option<(Kind, PathBuf)> {
+        let repository = Path::new(env!("CARGO_MANIFEST_DIR")).join("..");
+        Layout::find(&repository.join(dir)).map(|layout| {
+            (
+                layout.kind,
+                layout.root.strip_prefix(&repository).unwrap().into(),
+            )
+        })
+    }
+
+    #[test]
+    fn layouts() {
+        let layouts = [
+            (
+                "aoc_module_per_day/days/src/day03",
+                Kind::ModulePerDay,
+                "aoc_module_per_day/days",
+            ),
+            (
+                "aoc_workspace/days_ws/day03/src",
+                Kind::Workspace,
+                "aoc_workspace/days_ws",
+            ),
+            (
+                "aoc_workspace/days_ws/runner",
+                Kind::Workspace,
+                "aoc_workspace/days_ws",
+            ),
+            (
+                "aoc_project_per_day/day03/src",
+                Kind::ProjectPerDay,
+                "aoc_project_per_day",
+            ),
+            (
+                "aoc_project_per_day",
+                Kind::ProjectPerDay,
+                "aoc_project_per_day",
+            ),
+            (
+                "aoc_bin_per_day/src/bin/day03",
+                Kind::BinPerDay,
+                "aoc_bin_per_day",
+            ),
+        ];
+        for (dir, kind, root) in layouts {
+            assert_eq!(find(dir), Some((kind, PathBuf::from(root))), "{}", dir);
+        }
+        assert!(find("cargo_aoc").is_none());
+    }
+    #[test]
+    fn commands() -> Result<(), String> {
+        let args = |kind, action, day| -> Result<Vec<String>, String> {
+            let layout = Layout {
+                kind,
+                root: PathBuf::from("."),
+            };
+            let commands = layout.commands(action, day)?;
+            let (_, command) = &commands[0];
+            Ok(command
+                .get_args()
+                .map(|a| a.to_string_lossy().into_owned())
+                .collect())
+        };
+        assert_eq!(
+            args(Kind::ModulePerDay, Action::Run(Some(2)), Some(5))?,
+            ["run", "--", "day", "5", "2"]
+        );
+        assert_eq!(
+            args(Kind::Workspace, Action::Run(None), None)?,
+            ["run", "-p", "runner", "--", "all"]
+        );
+        assert_eq!(
+            args(Kind::ModulePerDay, Action::Test, Some(5))?,
+            ["test", "day05::"]
+        );
+        assert_eq!(
+            args(Kind::BinPerDay, Action::Bench, Some(5))?,
+            ["bench", "--bin", "day05"]
+        );
+        assert_eq!(
+            args(Kind::Workspace, Action::Test, None)?,
+            ["test", "--workspace"]
+        );
+        assert!(args(Kind::ModulePerDay, Action::Run(Some(2)), None).is_err());
+        assert!(args(Kind::ProjectPerDay, Action::Run(Some(2)), None).is_err());
+        Ok(())
+    }
+}
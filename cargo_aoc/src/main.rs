@@ -0,0 +1,61 @@
+mod layout;
+mod new;
+
+use layout::{Action, Layout};
+use std::{env, process::ExitCode};
+use structopt::StructOpt;
+
+// cargo runs `cargo-aoc aoc <args>` for `cargo aoc <args>`
+#[derive(Debug, StructOpt)]
+#[structopt(bin_name = "cargo")]
+enum Cargo {
+    /// Runs, tests and adds days in any of the four layouts
+    Aoc(Command),
+}
+
+#[derive(Debug, StructOpt)]
+enum Command {
+    /// solves a day (all days without one) on its input.txt
+    Run { day: Option<u8>, part: Option<u8> },
+    /// runs the tests of a day (all days without one)
+    Test { day: Option<u8> },
+    /// runs the benchmarks of a day (all days without one)
+    Bench { day: Option<u8> },
+    /// adds a day to solutions/ and all layouts
+    New { day: u8 },
+}
+
+fn main() -> ExitCode {
+    let Cargo::Aoc(command) = Cargo::from_args();
+    let cwd = env::current_dir().expect("current directory");
+    let result = match command {
+        Command::New { day } => new::new_day(&cwd, day),
+        Command::Run { day, part } => run(&cwd, Action::Run(part), day),
+        Command::Test { day } => run(&cwd, Action::Test, day),
+        Command::Bench { day } => run(&cwd, Action::Bench, day),
+    };
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(msg) => {
+            eprintln!("Error: {}", msg);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn run(cwd: &std::path::Path, action: Action, day: Option<u8>) -> Result<(), String> {
+    let layout =
+        Layout::find(cwd).ok_or(format!("no AoC layout found in {} or above", cwd.display()))?;
+    for (label, mut command) in layout.commands(action, day)? {
+        if let Some(label) = label {
+            println!("{}", label);
+        }
+        let status = command
+            .status()
+            .map_err(|err| format!("running cargo failed: {}", err))?;
+        if !status.success() {
+            return Err(format!("{:?} failed: {}", command, status));
+        }
+    }
+    Ok(())
+}
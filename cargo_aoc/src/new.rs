@@ -0,0 +1,285 @@
+use crate::layout::day_dirs;
+use std::{
+    env, fs,
+    path::{Path, PathBuf},
+    process::Command,
+};
+
+// A new day is written to solutions/dayNN (see aoc_gen), which copies it into the four
+// layouts. The workspace and project per day layouts additionally get a package for the
+// day (copied from the latest day), the module per day layout a feature for it. Every
+// file is prepared before the first one is written, so a layout that can't take the day
+// leaves the repository as it was.
+
+const SOLUTION: &str = r#"use common::anyhow::{anyhow, Result};
+use common::{aoc, aoc_parser, Answer};
+
+pub fn part_one(input: &str) -> Result<Answer> {
+    let input = parse_input(input)?;
+    solve_one(&input)
+}
+
+pub fn part_two(input: &str) -> Result<Answer> {
+    let input = parse_input(input)?;
+    solve_two(&input)
+}
+
+struct Input {
+    lines: Vec<String>,
+}
+
+#[aoc_parser]
+fn parse_input(input: &str) -> Result<Input> {
+    let lines = input.lines().map(String::from).collect();
+    Ok(Input { lines })
+}
+
+#[aoc(day = {number}, part = 1)]
+fn solve_one(input: &Input) -> Result<Answer> {
+    Err(anyhow!("not solved yet ({} lines)", input.lines.len()))
+}
+
+#[aoc(day = {number}, part = 2)]
+fn solve_two(input: &Input) -> Result<Answer> {
+    Err(anyhow!("not solved yet ({} lines)", input.lines.len()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::test_utils::*;
+    use std::sync::LazyLock;
+    use test::Bencher;
+
+    static TEST: LazyLock<String> = local_file!("test.txt");
+    static INPUT: LazyLock<String> = local_file!("input.txt");
+
+    #[test]
+    #[ignore = "not solved yet"]
+    fn test_one() -> Result<()> {
+        let answer = super::part_one(&TEST)?;
+        assert_eq!(answer, Answer::Num(0));
+        Ok(())
+    }
+    #[test]
+    #[ignore = "not solved yet"]
+    fn part_one() -> Result<()> {
+        let answer = super::part_one(&INPUT)?;
+        assert_eq!(answer, Answer::Num(0));
+        Ok(())
+    }
+    #[test]
+    #[ignore = "not solved yet"]
+    fn test_two() -> Result<()> {
+        let answer = super::part_two(&TEST)?;
+        assert_eq!(answer, Answer::Num(0));
+        Ok(())
+    }
+    #[test]
+    #[ignore = "not solved yet"]
+    fn part_two() -> Result<()> {
+        let answer = super::part_two(&INPUT)?;
+        assert_eq!(answer, Answer::Num(0));
+        Ok(())
+    }
+
+    #[bench]
+    fn bench_part_one(b: &mut Bencher) {
+        b.iter(part_one)
+    }
+    #[bench]
+    fn bench_part_two(b: &mut Bencher) {
+        b.iter(part_two)
+    }
+}
+"#;
+
+const MODULE_PER_DAY: &str = "aoc_module_per_day/days";
+const WORKSPACE: &str = "aoc_workspace/days_ws";
+const PROJECT_PER_DAY: &str = "aoc_project_per_day";
+
+pub fn new_day(cwd: &Path, day: u8) -> Result<(), String> {
+    if !(1..=25).contains(&day) {
+        return Err(format!("there is no day {}", day));
+    }
+    let root = cwd
+        .ancestors()
+        .find(|dir| dir.join("solutions").is_dir() && dir.join("aoc_gen").is_dir())
+        .ok_or("new days are added to solutions/ and generated by aoc_gen, neither was found")?;
+    let name = format!("day{:02}", day);
+    let solution_dir = root.join("solutions").join(&name);
+    if solution_dir.exists() {
+        return Err(format!("{} already exists", solution_dir.display()));
+    }
+    let solution = SOLUTION.replace("{number}", &day.to_string());
+    let mut files = vec![
+        (solution_dir.join("solution.rs"), solution),
+        (solution_dir.join("input.txt"), String::new()),
+        (solution_dir.join("test.txt"), String::new()),
+    ];
+
+    // workspace: member, workspace dependency, runner dependency and link
+    let workspace = root.join(WORKSPACE);
+    files.push(new_package(&workspace, &name)?);
+    files.push(edited(&workspace.join("Cargo.toml"), |content| {
+        let content = insert_after_last(
+            content,
+            |line| line.trim_start().starts_with("\"day"),
+            &format!("    \"{}\",", name),
+        )?;
+        insert_after_last(
+            &content,
+            |line| line.starts_with("day") && line.contains("path"),
+            &format!("{} = {{ path = \"{}\" }}", name, name),
+        )
+    })?);
+    files.push(edited(&workspace.join("runner/Cargo.toml"), |content| {
+        insert_after_last(
+            content,
+            |line| line.starts_with("day"),
+            &format!("{} = {{ workspace = true }}", name),
+        )
+    })?);
+    files.push(edited(&workspace.join("runner/src/main.rs"), |content| {
+        insert_after_last(
+            content,
+            |line| line.starts_with("use day"),
+            &format!("use {} as _;", name),
+        )
+    })?);
+
+    // project per day: the package, module per day: the feature
+    files.push(new_package(&root.join(PROJECT_PER_DAY), &name)?);
+    files.push(edited(
+        &root.join(MODULE_PER_DAY).join("Cargo.toml"),
+        |content| {
+            insert_after_last(
+                content,
+                |line| line.starts_with("day") && line.contains(" = ["),
+                &format!("{} = []", name),
+            )
+        },
+    )?);
+
+    for (file, content) in &files {
+        let dir = file.parent().unwrap();
+        fs::create_dir_all(dir).map_err(io_error(dir))?;
+        write(file, content)?;
+    }
+    // aoc_gen writes the sources of the new packages
+    for layout in [WORKSPACE, PROJECT_PER_DAY] {
+        let src = root.join(layout).join(&name).join("src");
+        fs::create_dir_all(&src).map_err(io_error(&src))?;
+    }
+
+    let status = Command::new(env::var("CARGO").unwrap_or(String::from("cargo")))
+        .args(["run", "-q"])
+        .current_dir(root.join("aoc_gen"))
+        .status()
+        .map_err(|err| format!("running aoc_gen failed: {}", err))?;
+    if !status.success() {
+        return Err(format!("aoc_gen failed: {}", status));
+    }
+    println!(
+        "added {}, edit {}",
+        name,
+        solution_dir.join("solution.rs").display()
+    );
+    Ok(())
+}
+
+// the Cargo.toml copied from the latest day, the sources are written by aoc_gen
+fn new_package(dir: &Path, name: &str) -> Result<(PathBuf, String), String> {
+    let (_, latest) = day_dirs(dir, "Cargo.toml")
+        .pop()
+        .ok_or(format!("no day packages in {}", dir.display()))?;
+    let latest_name = latest.file_name().unwrap().to_string_lossy().into_owned();
+    let manifest = fs::read_to_string(latest.join("Cargo.toml")).map_err(io_error(&latest))?;
+    let manifest = manifest.replacen(
+        &format!("name = \"{}\"", latest_name),
+        &format!("name = \"{}\"", name),
+        1,
+    );
+    Ok((dir.join(name).join("Cargo.toml"), manifest))
+}
+
+// inserts new_lines after the last line matching, keeping the rest as it is
+fn insert_after_last(
+    content: &str,
+    matches: impl Fn(&str) -> bool,
+    new_lines: &str,
+) -> Result<String, String> {
+    let lines: Vec<&str> = content.split_inclusive('\n').collect();
+    let last = lines
+        .iter()
+        .rposition(|line| matches(line.trim_end()))
+        .ok_or(format!("no place found for `{}`", new_lines.trim()))?;
+    let mut result: String = lines[..=last].concat();
+    if !result.ends_with('\n') {
+        result.push('\n');
+    }
+    result.push_str(new_lines);
+    result.push('\n');
+    result.push_str(&lines[last + 1..].concat());
+    Ok(result)
+}
+
+// the file and its changed content, nothing is written yet
+fn edited(
+    file: &Path,
+    change: impl FnOnce(&str) -> Result<String, String>,
+) -> Result<(PathBuf, String), String> {
+    let content = fs::read_to_string(file).map_err(io_error(file))?;
+    let changed = change(&content).map_err(|msg| format!("{}: {}", file.display(), msg))?;
+    Ok((file.to_path_buf(), changed))
+}
+
+fn write(file: &Path, content: &str) -> Result<(), String> {
+    fs::write(file, content).map_err(io_error(file))
+}
+
+fn io_error(path: &Path) -> impl Fn(std::io::Error) -> String {
+    let path = PathBuf::from(path);
+    move |err| format!("{}: {}", path.display(), err)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn insert_lines() -> Result<(), String> {
+        let members = "members = [\n    \"day01\",\n    \"day02\",\n    \"runner\",\n]\n";
+        let is_day = |line: &str| line.trim_start().starts_with("\"day");
+        assert_eq!(
+            insert_after_last(members, is_day, "    \"day03\",")?,
+            "members = [\n    \"day01\",\n    \"day02\",\n    \"day03\",\n    \"runner\",\n]\n"
+        );
+        assert_eq!(
+            insert_after_last(
+                "[features]\nday01 = []",
+                |l| l.starts_with("day"),
+                "day02 = []"
+            )?,
+            "[features]\nday01 = []\nday02 = []\n"
+        );
+        assert!(insert_after_last("runner", is_day, "day03").is_err());
+        Ok(())
+    }
+    #[test]
+    fn nothing_written_on_error() {
+        let root = env::temp_dir().join(format!("cargo-aoc-new-{}", std::process::id()));
+        fs::create_dir_all(root.join("solutions")).unwrap();
+        fs::create_dir_all(root.join("aoc_gen")).unwrap();
+        // there are no layouts to add the day to
+        assert!(new_day(&root, 6).is_err());
+        assert!(!root.join("solutions/day06").exists());
+        fs::remove_dir_all(&root).unwrap();
+    }
+    #[test]
+    fn template() {
+        let solution = SOLUTION.replace("{number}", "6");
+        assert!(solution.contains("#[aoc(day = 6, part = 2)]\nfn solve_two"));
+        assert!(!solution.contains("{number}"));
+    }
+}
@@ -0,0 +1,135 @@
+#![feature(test)]
+extern crate test;
+
+// common::parse against the regex and split_whitespace parsing the days used before,
+// on generated inputs shaped like the puzzle inputs: cargo bench --bench parse
+use common::{parse, regx};
+use std::{fmt::Write, sync::LazyLock};
+use test::{black_box, Bencher};
+
+// xorshift, the same inputs on every run
+struct Random(u64);
+
+impl Random {
+    fn below(&mut self, n: u64) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0 % n
+    }
+}
+
+// 140 lines of 140 characters, numbers and symbols between dots
+static DAY03: LazyLock<String> = LazyLock::new(|| {
+    let mut random = Random(3);
+    let mut input = String::new();
+    for _ in 0..140 {
+        let mut line = String::new();
+        while line.len() < 140 {
+            match random.below(6) {
+                0 => line.push_str(&random.below(1000).to_string()),
+                1 => line.push(['*', '#', '+', '$', '-'][random.below(5) as usize]),
+                _ => line.push('.'),
+            }
+        }
+        line.truncate(140);
+        writeln!(input, "{}", line).unwrap();
+    }
+    input
+});
+
+// 200 cards of 10 winning numbers and 25 numbers
+static DAY04: LazyLock<String> = LazyLock::new(|| {
+    let mut random = Random(4);
+    let mut input = String::new();
+    for id in 1..=200 {
+        write!(input, "Card {:>3}:", id).unwrap();
+        for _ in 0..10 {
+            write!(input, " {:>2}", random.below(100)).unwrap();
+        }
+        input.push_str(" |");
+        for _ in 0..25 {
+            write!(input, " {:>2}", random.below(100)).unwrap();
+        }
+        input.push('\n');
+    }
+    input
+});
+
+// 20 seeds and 7 maps of 40 ranges with 10 digit numbers
+static DAY05: LazyLock<String> = LazyLock::new(|| {
+    let mut random = Random(5);
+    let mut input = String::from("seeds:");
+    for _ in 0..20 {
+        write!(input, " {}", random.below(4_000_000_000)).unwrap();
+    }
+    for map in 0..7 {
+        write!(input, "\n\nmap-{}-to-{} map:", map, map + 1).unwrap();
+        for _ in 0..40 {
+            let mut number = || random.below(4_000_000_000);
+            write!(input, "\n{} {} {}", number(), number(), number()).unwrap();
+        }
+    }
+    input.push('\n');
+    input
+});
+
+#[bench]
+fn day03_numbers_regex(b: &mut Bencher) {
+    b.iter(|| {
+        regx!(r"\d+")
+            .find_iter(black_box(DAY03.as_str()))
+            .map(|m| (m.as_str().parse::<i128>().unwrap(), m.start()..m.end()))
+            .collect::<Vec<_>>()
+    })
+}
+#[bench]
+fn day03_numbers_parse(b: &mut Bencher) {
+    b.iter(|| {
+        parse::integers::<i128>(black_box(DAY03.as_str()))
+            .spanned()
+            .collect::<Vec<_>>()
+    })
+}
+
+#[bench]
+fn day04_numbers_split_whitespace(b: &mut Bencher) {
+    b.iter(|| {
+        black_box(DAY04.as_str())
+            .lines()
+            .flat_map(|line| line.split_whitespace().skip(2))
+            .filter_map(|s| s.parse::<usize>().ok())
+            .sum::<usize>()
+    })
+}
+#[bench]
+fn day04_numbers_parse(b: &mut Bencher) {
+    b.iter(|| {
+        parse::lines(black_box(DAY04.as_str()))
+            .flat_map(|line| parse::integers::<usize>(line).skip(1))
+            .sum::<usize>()
+    })
+}
+
+#[bench]
+fn day05_blocks_regex(b: &mut Bencher) {
+    b.iter(|| {
+        black_box(DAY05.as_str())
+            .split("\n\n")
+            .map(|block| {
+                regx!(r"\d+")
+                    .find_iter(block)
+                    .map(|m| m.as_str().parse::<usize>().unwrap())
+                    .sum::<usize>()
+            })
+            .sum::<usize>()
+    })
+}
+#[bench]
+fn day05_blocks_parse(b: &mut Bencher) {
+    b.iter(|| {
+        parse::blocks(black_box(DAY05.as_str()))
+            .map(|block| parse::integers::<usize>(block).sum::<usize>())
+            .sum::<usize>()
+    })
+}
@@ -0,0 +1,124 @@
+use crate::Answer;
+use std::{
+    env, fs,
+    path::{Path, PathBuf},
+    time::UNIX_EPOCH,
+};
+
+// The answers of earlier runs of the Solver, so `all` only solves what changed. An entry
+// is keyed by day, part and a hash of the input (see hash), in a directory per build of
+// the binary, so changed code never gets an old answer. A build is told apart by the
+// path, size and modification time of the executable, which cargo rewrites on every
+// build, reading a large binary to hash it would cost more than most parts:
+//
+//     target/release/aoc-cache/<executable>/<build>/day05-part1-<input>
+//
+// The directories of the executable's other builds are removed when a new one is created,
+// the other executables in the directory keep theirs. Errors are ignored, without a cache
+// everything is solved as usual.
+
+pub struct Cache {
+    dir: PathBuf,
+}
+
+impl Cache {
+    // next to the running executable
+    pub fn open() -> Option<Cache> {
+        let exe = env::current_exe().ok()?;
+        let metadata = fs::metadata(&exe).ok()?;
+        let modified = metadata.modified().ok()?.duration_since(UNIX_EPOCH).ok()?;
+        let build = format!(
+            "{}:{}:{}",
+            exe.display(),
+            metadata.len(),
+            modified.as_nanos()
+        );
+        let build = hash(build.as_bytes());
+        let builds = exe.with_file_name("aoc-cache").join(exe.file_stem()?);
+        Some(Cache::in_dir(builds.join(format!("{:016x}", build))))
+    }
+
+    pub fn in_dir(dir: impl Into<PathBuf>) -> Cache {
+        Cache { dir: dir.into() }
+    }
+
+    // input is the hash of the input
+    pub fn get(&self, day: u8, part: u8, input: u64) -> Option<Answer> {
+        let entry = fs::read_to_string(self.entry(day, part, input)).ok()?;
+        match entry.split_once(':')? {
+            ("num", number) => number.parse().ok().map(Answer::Num),
+            ("str", text) => Some(Answer::Str(String::from(text))),
+            _ => None,
+        }
+    }
+
+    pub fn insert(&self, day: u8, part: u8, input: u64, answer: &Answer) {
+        if !self.dir.is_dir() {
+            if let Some(builds) = self.dir.parent() {
+                remove_other_builds(builds, &self.dir);
+            }
+            if fs::create_dir_all(&self.dir).is_err() {
+                return;
+            }
+        }
+        let entry = match answer {
+            Answer::Num(number) => format!("num:{}", number),
+            Answer::Str(text) => format!("str:{}", text),
+        };
+        let _ = fs::write(self.entry(day, part, input), entry);
+    }
+
+    fn entry(&self, day: u8, part: u8, input: u64) -> PathBuf {
+        let name = format!("day{:02}-part{}-{:016x}", day, part, input);
+        self.dir.join(name)
+    }
+}
+
+fn remove_other_builds(builds: &Path, current: &Path) {
+    let Ok(entries) = fs::read_dir(builds) else {
+        return;
+    };
+    for entry in entries.flatten() {
+        if entry.path() != current {
+            let _ = fs::remove_dir_all(entry.path());
+        }
+    }
+}
+
+// FNV-1a, stable across Rust versions unlike DefaultHasher
+pub fn hash(bytes: &[u8]) -> u64 {
+    bytes.iter().fold(0xcbf29ce484222325, |hash, &byte| {
+        (hash ^ byte as u64).wrapping_mul(0x100000001b3)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn entries() {
+        let dir = env::temp_dir().join(format!("common-cache-{}", std::process::id()));
+        let (input, other_input) = (hash(b"input"), hash(b"other input"));
+        let other_exe = Cache::in_dir(dir.join("other/build"));
+        other_exe.insert(1, 1, input, &Answer::Num(7));
+        let old = Cache::in_dir(dir.join("days/old"));
+        old.insert(1, 1, input, &Answer::Num(1));
+        assert_eq!(old.get(1, 1, input), Some(Answer::Num(1)));
+        let cache = Cache::in_dir(dir.join("days/new"));
+        assert_eq!(cache.get(1, 1, input), None);
+        cache.insert(1, 1, input, &Answer::Num(-42));
+        cache.insert(1, 2, input, &Answer::from("123"));
+        cache.insert(1, 2, other_input, &Answer::from("a: b"));
+        assert_eq!(cache.get(1, 1, input), Some(Answer::Num(-42)));
+        assert_eq!(cache.get(1, 2, input), Some(Answer::from("123")));
+        assert_eq!(cache.get(1, 2, other_input), Some(Answer::from("a: b")));
+        assert_eq!(cache.get(2, 1, input), None);
+        // the old build is gone, the other executable's is kept
+        assert!(!dir.join("days/old").exists());
+        assert_eq!(other_exe.get(1, 1, input), Some(Answer::Num(7)));
+        fs::remove_dir_all(&dir).unwrap();
+        assert_eq!(hash(b""), 0xcbf29ce484222325);
+        assert_eq!(hash(b"a"), 0xaf63dc4c8601ec8c);
+    }
+}
@@ -0,0 +1,120 @@
+use std::{
+    fmt,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Mutex,
+    },
+};
+
+// Puzzle properties a solution relies on although the puzzle text doesn't promise them:
+//
+//     assume!(nums.len() <= 2, "a * touches at most two numbers", "{:?} touches {:?}", gear, nums);
+//
+// They are only checked after start() (e.g. by the runners' --check flag), a violation
+// is collected as a Diagnostic and the solution goes on as without the check. Without
+// start() an assumption costs an atomic load, the condition isn't evaluated.
+
+static ENABLED: AtomicBool = AtomicBool::new(false);
+static DIAGNOSTICS: Mutex<Vec<Diagnostic>> = Mutex::new(Vec::new());
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Diagnostic {
+    pub assumption: &'static str,
+    pub detail: String,
+    pub file: &'static str,
+    pub line: u32,
+}
+
+impl fmt::Display for Diagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} ({}:{})", self.assumption, self.file, self.line)?;
+        if !self.detail.is_empty() {
+            write!(f, ": {}", self.detail)?;
+        }
+        Ok(())
+    }
+}
+
+#[macro_export]
+macro_rules! assume {
+    ($condition:expr, $assumption:literal $(,)?) => {
+        $crate::assume!($condition, $assumption, "")
+    };
+    ($condition:expr, $assumption:literal, $($detail:tt)+) => {
+        if $crate::check::is_enabled() && !$condition {
+            $crate::check::report($crate::check::Diagnostic {
+                assumption: $assumption,
+                detail: format!($($detail)+),
+                file: file!(),
+                line: line!(),
+            });
+        }
+    };
+}
+
+pub fn start() {
+    ENABLED.store(true, Ordering::Relaxed);
+}
+
+pub fn is_enabled() -> bool {
+    ENABLED.load(Ordering::Relaxed)
+}
+
+pub fn report(diagnostic: Diagnostic) {
+    DIAGNOSTICS.lock().unwrap().push(diagnostic);
+}
+
+// the violations since the last call, e.g. after each part
+pub fn take() -> Vec<Diagnostic> {
+    std::mem::take(&mut DIAGNOSTICS.lock().unwrap())
+}
+
+// the collector is global, tests taking from it run one at a time
+#[cfg(test)]
+pub(crate) fn serial() -> std::sync::MutexGuard<'static, ()> {
+    static SERIAL: Mutex<()> = Mutex::new(());
+    SERIAL
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // the collector is global, so everything is checked in one test
+    #[test]
+    fn diagnostics() {
+        let _serial = serial();
+        let mut evaluated = 0;
+        let mut holds = |holds| {
+            evaluated += 1;
+            holds
+        };
+        assume!(holds(false), "not checked yet");
+        start();
+        assume!(holds(true), "holds");
+        assume!(holds(false), "without detail");
+        let gear = (1, 3);
+        assume!(
+            holds(false),
+            "a * touches at most two numbers",
+            "{:?} touches 3",
+            gear
+        );
+        assert_eq!(evaluated, 3);
+        let diagnostics = take();
+        assert_eq!(diagnostics.len(), 2);
+        assert_eq!(diagnostics[0].assumption, "without detail");
+        let line = diagnostics[1].line;
+        assert_eq!(
+            diagnostics[1].to_string(),
+            format!(
+                "a * touches at most two numbers ({}:{}): (1, 3) touches 3",
+                file!(),
+                line
+            )
+        );
+        assert!(take().is_empty());
+    }
+}
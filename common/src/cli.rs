@@ -0,0 +1,401 @@
+use crate::{
+    check::{self, Diagnostic},
+    json_string, Answer, Solution,
+};
+use anyhow::{anyhow, Result};
+use std::{
+    env, fs, io,
+    panic::{self, AssertUnwindSafe},
+    path::PathBuf,
+    process::ExitCode,
+    time::{Duration, Instant},
+};
+
+// The command line of the day binaries (project per day, workspace, bin per day):
+//
+//     dayNN [--part 1|2] [--input <path>] [--format text|json] [--check]
+//
+// Without --input the puzzle input is read from stdin, without --part both parts are
+// solved. The text format prints `part1: <answer>` lines (errors and violated
+// assumptions go to stderr), the json format prints one record per part and line:
+//
+//     {"day":3,"part":1,"answer":"4361","parse_ns":52100,"solve_ns":8300,"error":null,"diagnostics":[]}
+//
+// answer is null if the part failed and error is {"kind":"parse"|"solve","message":"..."}
+// instead. solve_ns is null if the input could not be parsed. With --check the
+// assumptions of the day (see common::assume!) are checked, each violation while parsing
+// or solving the part is a {"assumption":"...","detail":"...","file":"...","line":N}
+// in diagnostics.
+//
+// The exit code tells what went wrong (following sysexits.h):
+
+pub const EXIT_USAGE: u8 = 64; // invalid arguments
+pub const EXIT_PARSE: u8 = 65; // the input could not be parsed
+pub const EXIT_NO_INPUT: u8 = 66; // the input could not be read
+pub const EXIT_SOLVE: u8 = 70; // solving a part failed
+
+const USAGE: &str = "usage: dayNN [--part 1|2] [--input <path>] [--format text|json] [--check]";
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Format {
+    Text,
+    Json,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct Args {
+    pub part: Option<u8>,
+    pub input: Option<PathBuf>,
+    pub format: Format,
+    pub check: bool,
+}
+
+impl Args {
+    pub fn parse(args: impl IntoIterator<Item = String>) -> Result<Args, String> {
+        let mut parsed = Args {
+            part: None,
+            input: None,
+            format: Format::Text,
+            check: false,
+        };
+        let mut args = args.into_iter();
+        while let Some(arg) = args.next() {
+            let mut value = || args.next().ok_or(format!("{} needs a value", arg));
+            match arg.as_str() {
+                "--part" => match value()?.as_str() {
+                    "1" => parsed.part = Some(1),
+                    "2" => parsed.part = Some(2),
+                    part => return Err(format!("invalid part {}", part)),
+                },
+                "--input" => parsed.input = Some(PathBuf::from(value()?)),
+                "--format" => match value()?.as_str() {
+                    "text" => parsed.format = Format::Text,
+                    "json" => parsed.format = Format::Json,
+                    format => return Err(format!("invalid format {}", format)),
+                },
+                "--check" => parsed.check = true,
+                _ => return Err(format!("unexpected argument {}", arg)),
+            }
+        }
+        Ok(parsed)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ErrorKind {
+    Parse,
+    Solve,
+}
+
+#[derive(Debug)]
+pub struct Record {
+    pub day: u8,
+    pub part: u8,
+    pub answer: Option<Answer>,
+    pub parse_time: Duration,
+    pub solve_time: Option<Duration>,
+    pub error: Option<(ErrorKind, String)>,
+    // the assumptions violated while parsing and solving the part
+    pub diagnostics: Vec<Diagnostic>,
+}
+
+impl Record {
+    pub fn to_json(&self) -> String {
+        let answer = match &self.answer {
+            Some(answer) => json_string(&answer.to_string()),
+            None => String::from("null"),
+        };
+        let solve_ns = match self.solve_time {
+            Some(time) => time.as_nanos().to_string(),
+            None => String::from("null"),
+        };
+        let error = match &self.error {
+            Some((kind, message)) => format!(
+                "{{\"kind\":\"{}\",\"message\":{}}}",
+                match kind {
+                    ErrorKind::Parse => "parse",
+                    ErrorKind::Solve => "solve",
+                },
+                json_string(message)
+            ),
+            None => String::from("null"),
+        };
+        let diagnostics: Vec<String> = self
+            .diagnostics
+            .iter()
+            .map(|diagnostic| {
+                format!(
+                    "{{\"assumption\":{},\"detail\":{},\"file\":{},\"line\":{}}}",
+                    json_string(diagnostic.assumption),
+                    json_string(&diagnostic.detail),
+                    json_string(diagnostic.file),
+                    diagnostic.line
+                )
+            })
+            .collect();
+        format!(
+            "{{\"day\":{},\"part\":{},\"answer\":{},\"parse_ns\":{},\"solve_ns\":{},\"error\":{},\"diagnostics\":[{}]}}",
+            self.day,
+            self.part,
+            answer,
+            self.parse_time.as_nanos(),
+            solve_ns,
+            error,
+            diagnostics.join(",")
+        )
+    }
+}
+
+type PartFn<I> = fn(&I) -> Result<Answer>;
+
+// The main of a day binary, e.g. `common::cli::main(3, parse_input, solve_one, solve_two)`.
+// The input is kept until the process exits, so the parsed input may borrow from it.
+pub fn main<I>(
+    day: u8,
+    parse: fn(&'static str) -> Result<I>,
+    one: PartFn<I>,
+    two: PartFn<I>,
+) -> ExitCode {
+    let args = match Args::parse(env::args().skip(1)) {
+        Ok(args) => args,
+        Err(msg) => {
+            eprintln!("Error: {}\n{}", msg, USAGE);
+            return ExitCode::from(EXIT_USAGE);
+        }
+    };
+    if args.check {
+        check::start();
+    }
+    let input = match &args.input {
+        Some(file) => fs::read_to_string(file),
+        None => io::read_to_string(io::stdin()),
+    };
+    let input: &'static str = match input {
+        Ok(input) => input.leak(),
+        Err(err) => {
+            eprintln!("Error: reading the input failed: {}", err);
+            return ExitCode::from(EXIT_NO_INPUT);
+        }
+    };
+    let parts = match args.part {
+        Some(part) => vec![part],
+        None => vec![1, 2],
+    };
+    let records = solve(day, input, &parts, parse, [one, two]);
+    for record in records.iter() {
+        match args.format {
+            Format::Json => println!("{}", record.to_json()),
+            Format::Text => match (&record.answer, &record.error) {
+                (Some(answer), _) => println!("part{}: {}", record.part, answer),
+                (None, Some((_, message))) => eprintln!("Error: part{}: {}", record.part, message),
+                (None, None) => {}
+            },
+        }
+        if args.format == Format::Text {
+            for diagnostic in &record.diagnostics {
+                eprintln!("part{}: assumption violated: {}", record.part, diagnostic);
+            }
+        }
+    }
+    exit_code(&records)
+}
+
+// the main of a day binary implementing Solution, e.g. `common::cli::solution_main::<Day03>(3)`
+pub fn solution_main<S: Solution>(day: u8) -> ExitCode {
+    main(
+        day,
+        S::parse,
+        |input| Ok(S::part_one(input)?.into()),
+        |input| Ok(S::part_two(input)?.into()),
+    )
+}
+
+pub fn solve<'a, I>(
+    day: u8,
+    input: &'a str,
+    parts: &[u8],
+    parse: fn(&'a str) -> Result<I>,
+    solvers: [PartFn<I>; 2],
+) -> Vec<Record> {
+    let start = Instant::now();
+    let parsed = catch_panic(|| parse(input));
+    let parse_time = start.elapsed();
+    let parse_diagnostics = check::take();
+    parts
+        .iter()
+        .map(|&part| {
+            let mut record = Record {
+                day,
+                part,
+                answer: None,
+                parse_time,
+                solve_time: None,
+                error: None,
+                diagnostics: parse_diagnostics.clone(),
+            };
+            match &parsed {
+                Ok(parsed) => {
+                    let start = Instant::now();
+                    let answer = catch_panic(|| solvers[part as usize - 1](parsed));
+                    record.solve_time = Some(start.elapsed());
+                    match answer {
+                        Ok(answer) => record.answer = Some(answer),
+                        Err(err) => record.error = Some((ErrorKind::Solve, err.to_string())),
+                    }
+                }
+                Err(err) => record.error = Some((ErrorKind::Parse, err.to_string())),
+            }
+            record.diagnostics.extend(check::take());
+            record
+        })
+        .collect()
+}
+
+// most days unwrap while parsing, a panic is reported like any other error
+fn catch_panic<T>(f: impl FnOnce() -> Result<T>) -> Result<T> {
+    match panic::catch_unwind(AssertUnwindSafe(f)) {
+        Ok(result) => result,
+        Err(payload) => {
+            let message = if let Some(s) = payload.downcast_ref::<&str>() {
+                s.to_string()
+            } else if let Some(s) = payload.downcast_ref::<String>() {
+                s.clone()
+            } else {
+                String::from("unknown panic payload")
+            };
+            Err(anyhow!("panicked: {}", message))
+        }
+    }
+}
+
+fn exit_code(records: &[Record]) -> ExitCode {
+    let kinds: Vec<ErrorKind> = records
+        .iter()
+        .filter_map(|r| r.error.as_ref().map(|(kind, _)| *kind))
+        .collect();
+    if kinds.contains(&ErrorKind::Parse) {
+        ExitCode::from(EXIT_PARSE)
+    } else if kinds.contains(&ErrorKind::Solve) {
+        ExitCode::from(EXIT_SOLVE)
+    } else {
+        ExitCode::SUCCESS
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(args: &[&str]) -> Result<Args, String> {
+        Args::parse(args.iter().map(|s| String::from(*s)))
+    }
+    fn parse(input: &str) -> Result<i128> {
+        Ok(input.trim().parse()?)
+    }
+    fn double(input: &i128) -> Result<Answer> {
+        Ok(Answer::Num(input * 2))
+    }
+    fn fail(_: &i128) -> Result<Answer> {
+        Err(anyhow!("no \"solution\""))
+    }
+
+    #[test]
+    fn arguments() {
+        assert_eq!(
+            args(&[]),
+            Ok(Args {
+                part: None,
+                input: None,
+                format: Format::Text,
+                check: false
+            })
+        );
+        assert_eq!(
+            args(&["--format", "json", "--part", "2", "--input", "in.txt", "--check"]),
+            Ok(Args {
+                part: Some(2),
+                input: Some(PathBuf::from("in.txt")),
+                format: Format::Json,
+                check: true
+            })
+        );
+        assert!(args(&["--part", "3"]).is_err());
+        assert!(args(&["--input"]).is_err());
+        assert!(args(&["input.txt"]).is_err());
+    }
+    #[test]
+    fn records() {
+        let _serial = check::serial();
+        let records = solve(7, "21\n", &[1, 2], parse, [double, fail]);
+        assert_eq!(records[0].answer, Some(Answer::Num(42)));
+        assert_eq!(records[0].error, None);
+        assert_eq!(records[1].answer, None);
+        assert_eq!(
+            records[1].error,
+            Some((ErrorKind::Solve, String::from("no \"solution\"")))
+        );
+        let json = records[1].to_json();
+        assert!(json.starts_with("{\"day\":7,\"part\":2,\"answer\":null,\"parse_ns\":"));
+        assert!(json.ends_with(
+            ",\"error\":{\"kind\":\"solve\",\"message\":\"no \\\"solution\\\"\"},\"diagnostics\":[]}"
+        ));
+        assert_eq!(exit_code(&records), ExitCode::from(EXIT_SOLVE));
+    }
+    #[test]
+    fn borrowed_input() {
+        let _serial = check::serial();
+        let input = String::from("ab c");
+        let words = |input| Ok(str::split_whitespace(input).collect::<Vec<&str>>());
+        let first = |words: &Vec<&str>| Ok(Answer::from(words[0]));
+        let records = solve(7, &input, &[1, 2], words, [first, first]);
+        assert_eq!(records[1].answer, Some(Answer::from("ab")));
+    }
+    #[test]
+    fn parse_errors() {
+        let _serial = check::serial();
+        let records = solve(7, "x", &[2], parse, [double, double]);
+        assert_eq!(records.len(), 1);
+        assert_eq!(records[0].part, 2);
+        assert_eq!(records[0].solve_time, None);
+        assert!(matches!(records[0].error, Some((ErrorKind::Parse, _))));
+        assert!(records[0].to_json().contains("\"solve_ns\":null"));
+        assert_eq!(exit_code(&records), ExitCode::from(EXIT_PARSE));
+    }
+    #[test]
+    fn panics() {
+        let _serial = check::serial();
+        let records = solve(7, "21", &[1], parse, [|_| panic!("at the disco"), double]);
+        let (kind, message) = records[0].error.as_ref().unwrap();
+        assert_eq!(*kind, ErrorKind::Solve);
+        assert_eq!(message, "panicked: at the disco");
+    }
+    #[test]
+    fn diagnostics() {
+        let _serial = check::serial();
+        check::start();
+        let parse = |input: &str| {
+            crate::assume!(input.len() < 2, "short input", "{} bytes", input.len());
+            parse(input)
+        };
+        let small = |n: &i128| {
+            crate::assume!(*n < 10, "small numbers");
+            double(n)
+        };
+        let records = solve(7, "21", &[1, 2], parse, [small, double]);
+        let assumptions = |record: &Record| {
+            let diagnostics = record.diagnostics.iter();
+            diagnostics.map(|d| d.assumption).collect::<Vec<_>>()
+        };
+        assert_eq!(assumptions(&records[0]), ["short input", "small numbers"]);
+        assert_eq!(assumptions(&records[1]), ["short input"]);
+        let json = records[1].to_json();
+        assert!(json.contains(
+            ",\"diagnostics\":[{\"assumption\":\"short input\",\"detail\":\"2 bytes\",\"file\":"
+        ));
+        assert!(check::take().is_empty());
+    }
+    #[test]
+    fn json_strings() {
+        assert_eq!(json_string("a\"b\\c\nd\u{1}"), "\"a\\\"b\\\\c\\nd\\u0001\"");
+    }
+}
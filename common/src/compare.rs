@@ -0,0 +1,174 @@
+use crate::{
+    solver::{print_part_prefix, print_part_result},
+    Answer,
+};
+use anyhow::{anyhow, Result};
+use std::{
+    collections::BTreeMap,
+    io::{Read, Write},
+    process::{Command, ExitCode, Stdio},
+    thread,
+    time::{Duration, Instant},
+};
+
+// Differential testing against solutions written in other languages: the external
+// command gets the input on stdin like our day binaries and is expected to print
+// `part1: <answer>` and `part2: <answer>` lines (anything else is ignored).
+
+// the exit code of the runners if the answers differ (or couldn't be compared)
+pub const EXIT_MISMATCH: u8 = 1;
+
+pub fn exit_code(all_match: bool) -> ExitCode {
+    if all_match {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::from(EXIT_MISMATCH)
+    }
+}
+
+// prints one line per part comparing our answer with the external one, returns
+// whether all parts matched
+pub fn compare(
+    day: u8,
+    mut ours: impl FnMut(u8) -> Option<Result<Answer>>,
+    input: &str,
+    command: &[String],
+    timeout: Duration,
+) -> bool {
+    let theirs = run_external(command, input, timeout).map(|stdout| parse_part_lines(&stdout));
+    let mut all_match = true;
+    for part in [1, 2] {
+        let ours = match ours(part) {
+            Some(Ok(answer)) => answer,
+            result => {
+                print_part_prefix(day, part);
+                print_part_result(result);
+                all_match = false;
+                continue;
+            }
+        };
+        print_part_prefix(day, part);
+        match theirs.as_ref().map(|answers| answers.get(&part)) {
+            Ok(Some(answer)) if *answer == ours => println!("{} (matches)", ours),
+            Ok(Some(answer)) => {
+                println!("MISMATCH ours {}, external {}", ours, answer);
+                all_match = false;
+            }
+            Ok(None) => {
+                println!("Err: external command printed no part{} line", part);
+                all_match = false;
+            }
+            Err(err) => {
+                println!("Err: external command failed: {}", err);
+                all_match = false;
+            }
+        }
+    }
+    all_match
+}
+
+// runs the command with input on stdin and returns its stdout, the command is
+// killed if it doesn't finish in time
+pub fn run_external(command: &[String], input: &str, timeout: Duration) -> Result<String> {
+    let (program, args) = command.split_first().ok_or(anyhow!("no command given"))?;
+    let mut child = Command::new(program)
+        .args(args)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::inherit())
+        .spawn()
+        .map_err(|err| anyhow!("starting {} failed: {}", program, err))?;
+    let mut stdin = child.stdin.take().unwrap();
+    let input = String::from(input);
+    // the command may exit without reading everything, so a failed write is ignored
+    thread::spawn(move || stdin.write_all(input.as_bytes()));
+    let mut stdout = child.stdout.take().unwrap();
+    let reader = thread::spawn(move || {
+        let mut output = String::new();
+        stdout.read_to_string(&mut output).map(|_| output)
+    });
+    let start = Instant::now();
+    let status = loop {
+        if let Some(status) = child.try_wait()? {
+            break status;
+        }
+        if start.elapsed() > timeout {
+            child.kill()?;
+            child.wait()?;
+            return Err(anyhow!("timed out after {:?}", timeout));
+        }
+        thread::sleep(Duration::from_millis(10));
+    };
+    let output = reader
+        .join()
+        .map_err(|_| anyhow!("reading the output panicked"))??;
+    if !status.success() {
+        return Err(anyhow!("{} exited with {}", program, status));
+    }
+    Ok(output)
+}
+
+pub fn parse_part_lines(stdout: &str) -> BTreeMap<u8, Answer> {
+    stdout
+        .lines()
+        .filter_map(|line| {
+            let (part, answer) = line.trim().strip_prefix("part")?.split_once(':')?;
+            Some((part.parse().ok()?, answer.trim().parse().ok()?))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sh(script: &str) -> Vec<String> {
+        vec![String::from("sh"), String::from("-c"), String::from(script)]
+    }
+
+    #[test]
+    fn part_lines() {
+        let answers = parse_part_lines("debug\npart1: 54450\n  part2:ABC\npartX: 1\n");
+        assert_eq!(answers.len(), 2);
+        assert_eq!(answers[&1], Answer::Num(54450));
+        assert_eq!(answers[&2], Answer::Str(String::from("ABC")));
+    }
+    #[cfg(unix)]
+    #[test]
+    fn external_command() -> Result<()> {
+        let timeout = Duration::from_secs(10);
+        let output = run_external(&sh("read x; echo part1: $x"), "42\n", timeout)?;
+        assert_eq!(output, "part1: 42\n");
+        let failed = run_external(&sh("exit 3"), "", timeout);
+        assert!(failed.unwrap_err().to_string().contains("exit status: 3"));
+        Ok(())
+    }
+    #[cfg(unix)]
+    #[test]
+    fn timeout() {
+        let timeout = Duration::from_millis(100);
+        let result = run_external(&sh("sleep 5"), "", timeout);
+        assert!(result.unwrap_err().to_string().starts_with("timed out"));
+    }
+    #[cfg(unix)]
+    #[test]
+    fn mismatches() {
+        let ours = |part| Some(Ok(Answer::Num(part as i128)));
+        let timeout = Duration::from_secs(10);
+        assert!(compare(
+            1,
+            ours,
+            "",
+            &sh("echo part1: 1; echo part2: 2"),
+            timeout
+        ));
+        assert!(!compare(
+            1,
+            ours,
+            "",
+            &sh("echo part1: 1; echo part2: 3"),
+            timeout
+        ));
+        assert!(!compare(1, ours, "", &sh("echo part1: 1"), timeout));
+    }
+}
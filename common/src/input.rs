@@ -0,0 +1,139 @@
+use std::{
+    cell::OnceCell,
+    fs::{self, File},
+    io::{self, BufRead, BufReader, Read},
+    ops::Deref,
+    path::{Path, PathBuf},
+};
+
+// An input file that is only read the way a part asks for it. Parts taking &str get
+// text() from the Solver, parts taking &InputFile can avoid holding the input in a
+// String, e.g. for generated inputs of a few gigabytes:
+//
+//     #[aoc(day = 5, part = 1)]
+//     fn solve_one_mapped(input: &InputFile) -> Result<Answer> {
+//         let bytes = input.bytes()?;                 // memory-mapped with the mmap feature
+//         solve_one(&parse_input(std::str::from_utf8(&bytes)?)?)
+//     }
+//
+// or go through input.lines()? one line at a time. An embedded input (compiled into the
+// binary) offers the same views without touching the disk.
+
+pub struct InputFile {
+    path: PathBuf,
+    text: OnceCell<String>,
+    embedded: Option<&'static str>,
+}
+
+pub enum Bytes {
+    #[cfg(feature = "mmap")]
+    Mapped(memmap2::Mmap),
+    Read(Vec<u8>),
+    Embedded(&'static [u8]),
+}
+
+impl InputFile {
+    pub fn new(path: impl Into<PathBuf>) -> InputFile {
+        InputFile {
+            path: path.into(),
+            text: OnceCell::new(),
+            embedded: None,
+        }
+    }
+
+    // the path is only shown, e.g. in errors
+    pub fn embedded(path: impl Into<PathBuf>, text: &'static str) -> InputFile {
+        InputFile {
+            embedded: Some(text),
+            ..InputFile::new(path)
+        }
+    }
+
+    pub fn is_embedded(&self) -> bool {
+        self.embedded.is_some()
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    // read once, later calls return the same text
+    pub fn text(&self) -> io::Result<&str> {
+        if let Some(text) = self.embedded {
+            return Ok(text);
+        }
+        if let Some(text) = self.text.get() {
+            return Ok(text);
+        }
+        let text = fs::read_to_string(&self.path)?;
+        Ok(self.text.get_or_init(|| text))
+    }
+
+    // the whole file without a copy with the mmap feature, read into memory otherwise
+    pub fn bytes(&self) -> io::Result<Bytes> {
+        if let Some(text) = self.embedded {
+            return Ok(Bytes::Embedded(text.as_bytes()));
+        }
+        #[cfg(feature = "mmap")]
+        {
+            let file = File::open(&self.path)?;
+            // mapping an empty file fails on some platforms
+            if file.metadata()?.len() > 0 {
+                // SAFETY: the mapping is only valid while nobody changes the file, inputs
+                // aren't written while they are solved
+                let map = unsafe { memmap2::Mmap::map(&file)? };
+                return Ok(Bytes::Mapped(map));
+            }
+        }
+        fs::read(&self.path).map(Bytes::Read)
+    }
+
+    // buffered, without the line endings ("\n" or "\r\n")
+    pub fn lines(&self) -> io::Result<impl Iterator<Item = io::Result<String>>> {
+        let reader: Box<dyn Read> = match self.embedded {
+            Some(text) => Box::new(text.as_bytes()),
+            None => Box::new(File::open(&self.path)?),
+        };
+        Ok(BufReader::new(reader).lines())
+    }
+}
+
+impl Deref for Bytes {
+    type Target = [u8];
+    fn deref(&self) -> &[u8] {
+        match self {
+            #[cfg(feature = "mmap")]
+            Bytes::Mapped(map) => map,
+            Bytes::Read(bytes) => bytes,
+            Bytes::Embedded(bytes) => bytes,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn views() -> io::Result<()> {
+        let path = std::env::temp_dir().join(format!("common-input-{}.txt", std::process::id()));
+        fs::write(&path, "seeds: 79 14\r\n\r\n50 98 2\r\n")?;
+        let input = InputFile::new(&path);
+        assert_eq!(&*input.bytes()?, b"seeds: 79 14\r\n\r\n50 98 2\r\n");
+        let lines = input.lines()?.collect::<io::Result<Vec<_>>>()?;
+        assert_eq!(lines, ["seeds: 79 14", "", "50 98 2"]);
+        let text = input.text()?;
+        assert!(text.starts_with("seeds"));
+        fs::write(&path, "")?;
+        assert!(input.bytes()?.is_empty());
+        // the text was read before
+        assert!(std::ptr::eq(text, input.text()?));
+        fs::remove_file(&path)?;
+        assert!(input.bytes().is_err());
+        let embedded = InputFile::embedded(&path, "1\r\n2\n");
+        assert_eq!(embedded.text()?, "1\r\n2\n");
+        assert_eq!(&*embedded.bytes()?, b"1\r\n2\n");
+        assert_eq!(embedded.lines()?.count(), 2);
+        Ok(())
+    }
+}
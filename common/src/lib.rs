@@ -0,0 +1,206 @@
+// re-export common dependencies
+pub use anyhow;
+#[cfg(feature = "regex")]
+pub use regex;
+
+use std::{convert::Infallible, fmt::Display, fmt::Write, str::FromStr};
+
+#[cfg(feature = "solver")]
+pub mod cache;
+pub mod check;
+#[cfg(feature = "cli")]
+pub mod cli;
+#[cfg(feature = "solver")]
+pub mod compare;
+#[cfg(feature = "ocr")]
+pub mod ocr;
+#[cfg(feature = "orchestrator")]
+pub mod orchestrator;
+#[cfg(feature = "macros")]
+pub mod registry;
+#[cfg(feature = "derive")]
+pub use aoc_macros::FromLine;
+#[cfg(feature = "macros")]
+pub use registry::{aoc, aoc_parser};
+pub mod input;
+pub mod matcher;
+pub mod parse;
+pub mod scan;
+#[cfg(feature = "solver")]
+pub mod solver;
+pub mod trace;
+
+// the return type for parts sometime its Numbers sometimes its Strings
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Answer {
+    Num(i128),
+    #[allow(dead_code)]
+    Str(String),
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self {
+            Answer::Num(i) => write!(f, "{}", i),
+            Answer::Str(s) => write!(f, "{}", s),
+        }
+    }
+}
+
+// the inverse of Display, used to read answers printed by other programs
+impl FromStr for Answer {
+    type Err = Infallible;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s.parse::<i128>() {
+            Ok(i) => Answer::Num(i),
+            Err(_) => Answer::Str(String::from(s)),
+        })
+    }
+}
+
+// the integer types days usually sum up into
+macro_rules! answer_from_integer {
+    ($($t:ty),*) => {$(
+        impl From<$t> for Answer {
+            fn from(n: $t) -> Self {
+                Answer::Num(n as i128)
+            }
+        }
+    )*};
+}
+answer_from_integer!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, usize);
+
+impl From<String> for Answer {
+    fn from(s: String) -> Self {
+        Answer::Str(s)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(s: &str) -> Self {
+        Answer::Str(String::from(s))
+    }
+}
+
+// A day with typed parts: they get the parsed input and return anything convertible to
+// Answer. Solver::add_solution (or #[aoc(day = N)] on the impl) and cli::solution_main
+// erase the types. The input may borrow from the text, e.g. `type Input<'a> = Vec<&'a str>;`
+pub trait Solution {
+    type Input<'a>;
+    fn parse(input: &str) -> anyhow::Result<Self::Input<'_>>;
+    fn part_one(input: &Self::Input<'_>) -> anyhow::Result<impl Into<Answer>>;
+    fn part_two(input: &Self::Input<'_>) -> anyhow::Result<impl Into<Answer>>;
+}
+
+// the parts of a Solution for one input, see solution_parts
+pub type SolutionFn = for<'a> fn(&'a str) -> Box<dyn FnMut(u8) -> anyhow::Result<Answer> + 'a>;
+
+// solves the parts of the input asked for, the input is parsed once for all of them
+pub fn solution_parts<S: Solution + 'static>(
+    input: &str,
+) -> Box<dyn FnMut(u8) -> anyhow::Result<Answer> + '_> {
+    let mut parsed = None;
+    Box::new(move |part| {
+        let parsed = parsed.get_or_insert_with(|| {
+            let _span = trace::span("parse");
+            S::parse(input)
+        });
+        // the error isn't Clone, every part reports the message
+        let parsed = parsed
+            .as_ref()
+            .map_err(|err| anyhow::anyhow!("{:#}", err))?;
+        let _span = trace::span("solve");
+        match part {
+            1 => Ok(S::part_one(parsed)?.into()),
+            2 => Ok(S::part_two(parsed)?.into()),
+            _ => Err(anyhow::anyhow!("there is no part {}", part)),
+        }
+    })
+}
+
+// for the hand written json of cli and trace
+pub(crate) fn json_string(s: &str) -> String {
+    let mut out = String::from("\"");
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if c.is_control() => write!(out, "\\u{:04x}", c as u32).unwrap(),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+    out
+}
+
+// compiled on first use, the pattern is checked at compile time (see aoc_macros)
+#[cfg(feature = "regex")]
+pub use aoc_macros::regx;
+
+#[cfg(feature = "test-utils")]
+pub mod test_utils {
+    use std::{
+        fs,
+        path::{Path, PathBuf},
+    };
+    pub fn read_from_file(filename: impl AsRef<Path>) -> String {
+        let filename = filename.as_ref();
+        println!("reading {}", filename.display());
+        fs::read_to_string(filename)
+            .unwrap_or_else(|msg| panic!("error reading {}: {}", filename.display(), msg))
+    }
+
+    // file!() is relative to the workspace root (if there is one), but tests are run
+    // inside the package directory, so strip leading components until the directory
+    // of the source file is found below the manifest directory.
+    pub fn local_path(manifest_dir: &str, source_file: &str, file: &str) -> PathBuf {
+        let manifest_dir = Path::new(manifest_dir);
+        let source_dir = Path::new(source_file).parent().unwrap_or(Path::new(""));
+        let mut components = source_dir.components();
+        loop {
+            let candidate = manifest_dir.join(components.as_path());
+            if candidate.is_dir() {
+                return candidate.join(file);
+            }
+            if components.next().is_none() {
+                return manifest_dir.join(source_dir).join(file);
+            }
+        }
+    }
+
+    // the first dayNN in the path
+    pub fn day_in_path(path: &str) -> Option<&str> {
+        path.match_indices("day").find_map(|(start, _)| {
+            let day = path.get(start..start + 5)?;
+            day[3..].bytes().all(|b| b.is_ascii_digit()).then_some(day)
+        })
+    }
+
+    #[macro_export]
+    macro_rules! extract_day_from_path {
+        () => {{
+            $crate::test_utils::day_in_path(module_path!())
+                .expect("macro is only valid inside paths containing 'day\\d{2}' pattern")
+        }};
+    }
+
+    // reads a file lying next to the source file that uses the macro
+    // e.g. src/day03/test.txt (module per day) or day03/src/test.txt (workspace)
+    #[macro_export]
+    macro_rules! local_file {
+        ($file:literal) => {
+            std::sync::LazyLock::new(|| {
+                $crate::test_utils::read_from_file($crate::test_utils::local_path(
+                    env!("CARGO_MANIFEST_DIR"),
+                    file!(),
+                    $file,
+                ))
+            })
+        };
+    }
+    // re-export macros such that test_utils::local_file path can be used.
+    pub use crate::{extract_day_from_path, local_file};
+}
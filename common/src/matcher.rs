@@ -0,0 +1,239 @@
+use std::iter::FusedIterator;
+
+// Finds several patterns at once, including overlapping matches (Aho-Corasick), e.g. the
+// spelled digits of day 1 where "twone" holds both "two" and "one":
+//
+//     let digits = Matcher::with_values([("one", 1), ("two", 2), ("1", 1), ("2", 2)]);
+//     let first = digits.first(line).unwrap();
+//     let last = digits.last(line).unwrap();
+//     let number = first.value * 10 + last.value;
+//
+// The patterns are compiled into a table with a transition for every byte, so a search
+// is a single pass over the haystack with one lookup per byte.
+
+const ALPHABET: usize = 256;
+
+pub struct Matcher<V = ()> {
+    // next[state * ALPHABET + byte]
+    next: Vec<u32>,
+    // the patterns ending in a state, longest first
+    outputs: Vec<Vec<usize>>,
+    lengths: Vec<usize>,
+    values: Vec<V>,
+    longest: usize,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct Match<'m, V> {
+    pub pattern: usize,
+    pub start: usize,
+    pub end: usize,
+    pub value: &'m V,
+}
+
+// manual impls, V doesn't need to be Clone
+impl<V> Clone for Match<'_, V> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+impl<V> Copy for Match<'_, V> {}
+
+impl Matcher<()> {
+    pub fn new<P: AsRef<[u8]>>(patterns: impl IntoIterator<Item = P>) -> Self {
+        Matcher::with_values(patterns.into_iter().map(|pattern| (pattern, ())))
+    }
+}
+
+impl<V> Matcher<V> {
+    // panics on an empty pattern, which would match everywhere
+    pub fn with_values<P: AsRef<[u8]>>(patterns: impl IntoIterator<Item = (P, V)>) -> Self {
+        const MISSING: u32 = u32::MAX;
+        let mut next = vec![MISSING; ALPHABET];
+        let mut outputs = vec![Vec::new()];
+        let mut lengths = Vec::new();
+        let mut values = Vec::new();
+        // the trie
+        for (index, (pattern, value)) in patterns.into_iter().enumerate() {
+            let pattern = pattern.as_ref();
+            assert!(!pattern.is_empty(), "Matcher: pattern {} is empty", index);
+            let mut state = 0;
+            for &byte in pattern {
+                let slot = state * ALPHABET + byte as usize;
+                if next[slot] == MISSING {
+                    next[slot] = outputs.len() as u32;
+                    next.extend([MISSING; ALPHABET]);
+                    outputs.push(Vec::new());
+                }
+                state = next[slot] as usize;
+            }
+            outputs[state].push(index);
+            lengths.push(pattern.len());
+            values.push(value);
+        }
+        // Breadth first, so the failure state (the longest proper suffix that is also in
+        // the trie) of a state is complete before the state itself. Missing transitions
+        // take the one of the failure state, which makes the trie a DFA.
+        let mut fail = vec![0; outputs.len()];
+        let mut queue = std::collections::VecDeque::new();
+        for slot in &mut next[..ALPHABET] {
+            match *slot {
+                MISSING => *slot = 0,
+                child => queue.push_back(child as usize),
+            }
+        }
+        while let Some(state) = queue.pop_front() {
+            let suffix = fail[state];
+            let inherited = outputs[suffix].clone();
+            outputs[state].extend(inherited);
+            for byte in 0..ALPHABET {
+                let slot = state * ALPHABET + byte;
+                let fallback = next[suffix * ALPHABET + byte];
+                match next[slot] {
+                    MISSING => next[slot] = fallback,
+                    child => {
+                        fail[child as usize] = fallback as usize;
+                        queue.push_back(child as usize);
+                    }
+                }
+            }
+        }
+        let longest = lengths.iter().copied().max().unwrap_or(0);
+        Matcher {
+            next,
+            outputs,
+            lengths,
+            values,
+            longest,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.lengths.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.lengths.is_empty()
+    }
+
+    // all matches ordered by their end, the longer one first for the same end
+    pub fn find_overlapping<'m, 'h>(
+        &'m self,
+        haystack: &'h (impl AsRef<[u8]> + ?Sized),
+    ) -> Overlapping<'m, 'h, V> {
+        Overlapping {
+            matcher: self,
+            haystack: haystack.as_ref(),
+            position: 0,
+            state: 0,
+            output: 0,
+        }
+    }
+
+    // the match starting first, the longest of those starting there
+    pub fn first(&self, haystack: &(impl AsRef<[u8]> + ?Sized)) -> Option<Match<'_, V>> {
+        let mut best: Option<Match<V>> = None;
+        for found in self.find_overlapping(haystack) {
+            if let Some(best) = best {
+                // later matches can't start before the best one anymore
+                if found.end > best.start + self.longest {
+                    break;
+                }
+            }
+            if best.is_none_or(|best| better(&found, &best, found.start < best.start)) {
+                best = Some(found);
+            }
+        }
+        best
+    }
+
+    // the match starting last, the longest of those starting there
+    pub fn last(&self, haystack: &(impl AsRef<[u8]> + ?Sized)) -> Option<Match<'_, V>> {
+        self.find_overlapping(haystack)
+            .fold(None, |best, found| match best {
+                Some(best) if !better(&found, &best, found.start > best.start) => Some(best),
+                _ => Some(found),
+            })
+    }
+}
+
+fn better<V>(found: &Match<V>, best: &Match<V>, starts_better: bool) -> bool {
+    starts_better || (found.start == best.start && found.end > best.end)
+}
+
+pub struct Overlapping<'m, 'h, V> {
+    matcher: &'m Matcher<V>,
+    haystack: &'h [u8],
+    // the bytes consumed so far, the end of the matches in outputs[state]
+    position: usize,
+    state: usize,
+    output: usize,
+}
+
+impl<'m, V> Iterator for Overlapping<'m, '_, V> {
+    type Item = Match<'m, V>;
+
+    fn next(&mut self) -> Option<Match<'m, V>> {
+        let matcher = self.matcher;
+        loop {
+            if let Some(&pattern) = matcher.outputs[self.state].get(self.output) {
+                self.output += 1;
+                return Some(Match {
+                    pattern,
+                    start: self.position - matcher.lengths[pattern],
+                    end: self.position,
+                    value: &matcher.values[pattern],
+                });
+            }
+            let &byte = self.haystack.get(self.position)?;
+            self.state = matcher.next[self.state * ALPHABET + byte as usize] as usize;
+            self.position += 1;
+            self.output = 0;
+        }
+    }
+}
+
+impl<V> FusedIterator for Overlapping<'_, '_, V> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn spans<'m, V: 'm>(matches: impl Iterator<Item = Match<'m, V>>) -> Vec<(usize, usize, usize)> {
+        matches.map(|m| (m.pattern, m.start, m.end)).collect()
+    }
+
+    #[test]
+    fn overlapping() {
+        let matcher = Matcher::new(["one", "two", "eight", "ne", "e"]);
+        assert_eq!(
+            spans(matcher.find_overlapping("twoneight")),
+            [(1, 0, 3), (0, 2, 5), (3, 3, 5), (4, 4, 5), (2, 4, 9)]
+        );
+        assert_eq!(spans(matcher.find_overlapping(b"xyz".as_slice())), []);
+        assert_eq!(spans(matcher.find_overlapping("")), []);
+        let repeated = Matcher::new(["aa"]);
+        assert_eq!(spans(repeated.find_overlapping("aaaa")).len(), 3);
+    }
+    #[test]
+    fn first_and_last() {
+        let digits = Matcher::with_values([("one", 1), ("two", 2), ("1", 1), ("8", 8)]);
+        let first = digits.first("xtwone8x").unwrap();
+        let last = digits.last("xtwone8x").unwrap();
+        assert_eq!((*first.value, *last.value), (2, 8));
+        assert_eq!(*digits.last("xtwonex").unwrap().value, 1);
+        assert_eq!(digits.first("nothing"), None);
+        // the longer pattern starts first although the shorter one ends first
+        let matcher = Matcher::new(["abcd", "bc", "ab"]);
+        let first = matcher.first("xabcd").unwrap();
+        assert_eq!((first.pattern, first.start, first.end), (0, 1, 5));
+        let last = matcher.last("xabcd").unwrap();
+        assert_eq!(last.pattern, 1);
+        assert_eq!(matcher.len(), 3);
+    }
+    #[test]
+    #[should_panic(expected = "pattern 1 is empty")]
+    fn empty_pattern() {
+        Matcher::new(["a", ""]);
+    }
+}
@@ -0,0 +1,238 @@
+use std::{error::Error, fmt::Display};
+
+// Some puzzles render their answer as block letters on a screen of `#` and `.`
+// (e.g. 2016 day 8, 2018 day 10, 2019 day 8/11, 2021 day 13, 2022 day 10).
+// There are two fonts in use: a small one that is 6 rows high (mostly 4 columns
+// wide) and a large one that is 10 rows high and 6 columns wide. The letters are
+// placed at a fixed pitch, which a 5 column `Y` fills without a blank column.
+
+const SMALL_HEIGHT: usize = 6;
+const LARGE_HEIGHT: usize = 10;
+const SMALL_PITCH: usize = 5;
+const LARGE_PITCH: usize = 8;
+
+#[rustfmt::skip]
+const SMALL_FONT: &[(char, [&str; SMALL_HEIGHT])] = &[
+    ('A', [".##.", "#..#", "#..#", "####", "#..#", "#..#"]),
+    ('B', ["###.", "#..#", "###.", "#..#", "#..#", "###."]),
+    ('C', [".##.", "#..#", "#...", "#...", "#..#", ".##."]),
+    ('E', ["####", "#...", "###.", "#...", "#...", "####"]),
+    ('F', ["####", "#...", "###.", "#...", "#...", "#..."]),
+    ('G', [".##.", "#..#", "#...", "#.##", "#..#", ".###"]),
+    ('H', ["#..#", "#..#", "####", "#..#", "#..#", "#..#"]),
+    ('I', ["###", ".#.", ".#.", ".#.", ".#.", "###"]),
+    ('J', ["..##", "...#", "...#", "...#", "#..#", ".##."]),
+    ('K', ["#..#", "#.#.", "##..", "#.#.", "#.#.", "#..#"]),
+    ('L', ["#...", "#...", "#...", "#...", "#...", "####"]),
+    ('O', [".##.", "#..#", "#..#", "#..#", "#..#", ".##."]),
+    ('P', ["###.", "#..#", "#..#", "###.", "#...", "#..."]),
+    ('R', ["###.", "#..#", "#..#", "###.", "#.#.", "#..#"]),
+    ('S', [".###", "#...", "#...", ".##.", "...#", "###."]),
+    ('U', ["#..#", "#..#", "#..#", "#..#", "#..#", ".##."]),
+    ('Y', ["#...#", "#...#", ".#.#.", "..#..", "..#..", "..#.."]),
+    ('Z', ["####", "...#", "..#.", ".#..", "#...", "####"]),
+];
+
+#[rustfmt::skip]
+const LARGE_FONT: &[(char, [&str; LARGE_HEIGHT])] = &[
+    ('A', ["..##..", ".#..#.", "#....#", "#....#", "#....#", "######", "#....#", "#....#", "#....#", "#....#"]),
+    ('B', ["#####.", "#....#", "#....#", "#....#", "#####.", "#....#", "#....#", "#....#", "#....#", "#####."]),
+    ('C', [".####.", "#....#", "#.....", "#.....", "#.....", "#.....", "#.....", "#.....", "#....#", ".####."]),
+    ('E', ["######", "#.....", "#.....", "#.....", "#####.", "#.....", "#.....", "#.....", "#.....", "######"]),
+    ('F', ["######", "#.....", "#.....", "#.....", "#####.", "#.....", "#.....", "#.....", "#.....", "#....."]),
+    ('G', [".####.", "#....#", "#.....", "#.....", "#.....", "#..###", "#....#", "#....#", "#...##", ".###.#"]),
+    ('H', ["#....#", "#....#", "#....#", "#....#", "######", "#....#", "#....#", "#....#", "#....#", "#....#"]),
+    ('J', ["...###", "....#.", "....#.", "....#.", "....#.", "....#.", "....#.", "#...#.", "#...#.", ".###.."]),
+    ('K', ["#....#", "#...#.", "#..#..", "#.#...", "##....", "##....", "#.#...", "#..#..", "#...#.", "#....#"]),
+    ('L', ["#.....", "#.....", "#.....", "#.....", "#.....", "#.....", "#.....", "#.....", "#.....", "######"]),
+    ('N', ["#....#", "##...#", "##...#", "#.#..#", "#.#..#", "#..#.#", "#..#.#", "#...##", "#...##", "#....#"]),
+    ('P', ["#####.", "#....#", "#....#", "#....#", "#####.", "#.....", "#.....", "#.....", "#.....", "#....."]),
+    ('R', ["#####.", "#....#", "#....#", "#....#", "#####.", "#..#..", "#...#.", "#...#.", "#....#", "#....#"]),
+    ('X', ["#....#", "#....#", ".#..#.", ".#..#.", "..##..", "..##..", ".#..#.", ".#..#.", "#....#", "#....#"]),
+    ('Z', ["######", ".....#", ".....#", "....#.", "...#..", "..#...", ".#....", "#.....", "#.....", "######"]),
+];
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum OcrError {
+    // only grids with the height of one of the fonts can be read
+    UnsupportedHeight(usize),
+    // glyph is the unrecognized pattern rendered with `#` and `.`
+    UnknownGlyph {
+        index: usize,
+        column: usize,
+        glyph: String,
+    },
+}
+
+impl Display for OcrError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            OcrError::UnsupportedHeight(height) => write!(
+                f,
+                "unsupported glyph height {} (expected {} or {})",
+                height, SMALL_HEIGHT, LARGE_HEIGHT
+            ),
+            OcrError::UnknownGlyph {
+                index,
+                column,
+                glyph,
+            } => write!(
+                f,
+                "unknown glyph #{} at column {}:\n{}",
+                index, column, glyph
+            ),
+        }
+    }
+}
+
+impl Error for OcrError {}
+
+// reads a grid rendered with `#` (or `█`) for lit pixels, anything else is unlit
+pub fn parse(rendered: &str) -> Result<String, OcrError> {
+    let grid: Vec<Vec<bool>> = rendered
+        .lines()
+        .map(|l| l.chars().map(|c| c == '#' || c == '█').collect())
+        .collect();
+    parse_grid(&grid)
+}
+
+pub fn parse_grid<R: AsRef<[bool]>>(grid: &[R]) -> Result<String, OcrError> {
+    let rows: Vec<&[bool]> = grid.iter().map(|r| r.as_ref()).collect();
+    let top = rows.iter().position(|r| r.contains(&true));
+    let bottom = rows.iter().rposition(|r| r.contains(&true));
+    let rows = match (top, bottom) {
+        (Some(top), Some(bottom)) => &rows[top..=bottom],
+        _ => return Err(OcrError::UnsupportedHeight(0)),
+    };
+    let height = rows.len();
+    let pitch = match height {
+        SMALL_HEIGHT => SMALL_PITCH,
+        LARGE_HEIGHT => LARGE_PITCH,
+        _ => return Err(OcrError::UnsupportedHeight(height)),
+    };
+    let width = rows.iter().map(|r| r.len()).max().unwrap_or(0);
+    let lit = |x: usize, y: usize| rows[y].get(x).copied().unwrap_or(false);
+    let lit_column = |x: usize| (0..height).any(|y| lit(x, y));
+
+    // the cells start at the first lit column, a glyph is the lit part of its cell
+    let mut answer = String::new();
+    let first = (0..width).find(|&x| lit_column(x)).unwrap_or(width);
+    for cell in (first..width).step_by(pitch) {
+        let columns = cell..(cell + pitch).min(width);
+        let Some(start) = columns.clone().find(|&x| lit_column(x)) else {
+            continue;
+        };
+        let end = columns.rev().find(|&x| lit_column(x)).unwrap() + 1;
+        let glyph: Vec<String> = (0..height)
+            .map(|y| {
+                (start..end)
+                    .map(|gx| if lit(gx, y) { '#' } else { '.' })
+                    .collect()
+            })
+            .collect();
+        let letter = match height {
+            SMALL_HEIGHT => lookup(SMALL_FONT, &glyph),
+            _ => lookup(LARGE_FONT, &glyph),
+        };
+        match letter {
+            Some(letter) => answer.push(letter),
+            None => {
+                return Err(OcrError::UnknownGlyph {
+                    index: answer.len(),
+                    column: start,
+                    glyph: glyph.join("\n"),
+                })
+            }
+        }
+    }
+    Ok(answer)
+}
+
+fn lookup<const H: usize>(font: &[(char, [&str; H])], glyph: &[String]) -> Option<char> {
+    font.iter()
+        .find(|(_, pattern)| pattern.iter().zip(glyph).all(|(p, g)| p == g))
+        .map(|(letter, _)| *letter)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // renders letters at the pitch of the font, like the puzzles do
+    fn render<const H: usize>(font: &[(char, [&str; H])], text: &str, pitch: usize) -> String {
+        let glyphs: Vec<&[&str; H]> = text
+            .chars()
+            .map(|c| &font.iter().find(|(l, _)| *l == c).unwrap().1)
+            .collect();
+        (0..H)
+            .map(|y| {
+                glyphs
+                    .iter()
+                    .map(|g| format!("{:.<pitch$}", g[y]))
+                    .collect::<String>()
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    #[test]
+    fn small_letters() {
+        let rendered = "\
+#..#.###..####.###.
+#..#..#...#....#..#
+####..#...###..#..#
+#..#..#...#....###.
+#..#..#...#....#...
+#..#.###..####.#...";
+        assert_eq!(parse(rendered), Ok(String::from("HIEP")));
+    }
+    #[test]
+    fn small_alphabet() {
+        let alphabet: String = SMALL_FONT.iter().map(|(l, _)| l).collect();
+        let rendered = render(SMALL_FONT, &alphabet, SMALL_PITCH);
+        assert_eq!(parse(&rendered), Ok(alphabet));
+    }
+    #[test]
+    fn large_alphabet() {
+        let alphabet: String = LARGE_FONT.iter().map(|(l, _)| l).collect();
+        let rendered = render(LARGE_FONT, &alphabet, LARGE_PITCH);
+        assert_eq!(parse(&rendered), Ok(alphabet));
+    }
+    #[test]
+    fn surrounding_blank_space() {
+        let rendered = format!("\n   \n{}\n\n", render(SMALL_FONT, "ZU", SMALL_PITCH))
+            .replace('.', " ")
+            .replace('\n', "\n   ");
+        assert_eq!(parse(&rendered), Ok(String::from("ZU")));
+    }
+    #[test]
+    fn y_without_gap() {
+        let rendered = render(SMALL_FONT, "YAY", SMALL_PITCH);
+        // no blank column between the Y and the A
+        assert!(rendered.starts_with("#...#.##..#...#\n#...##..#"));
+        assert_eq!(parse(&rendered), Ok(String::from("YAY")));
+    }
+    #[test]
+    fn unknown_glyph() {
+        let rendered = "\
+.##..#.
+#..#.#.
+#..#.#.
+####.##
+#..#.#.
+#..#.#.";
+        assert_eq!(
+            parse(rendered),
+            Err(OcrError::UnknownGlyph {
+                index: 1,
+                column: 5,
+                glyph: String::from("#.\n#.\n#.\n##\n#.\n#."),
+            })
+        );
+    }
+    #[test]
+    fn unsupported_height() {
+        assert_eq!(parse("#\n#\n#"), Err(OcrError::UnsupportedHeight(3)));
+        assert_eq!(parse("...\n..."), Err(OcrError::UnsupportedHeight(0)));
+    }
+}
@@ -0,0 +1,364 @@
+use crate::{
+    cli::{EXIT_PARSE, EXIT_SOLVE},
+    compare::{self, compare},
+    solver::{print_part_prefix, print_part_result, Opt, StructOpt},
+    Answer,
+};
+use anyhow::{anyhow, Result};
+use std::{
+    collections::BTreeMap,
+    env, fs,
+    path::{Path, PathBuf},
+    process::{Command, ExitCode, Stdio},
+    time::Duration,
+};
+
+// Runs the stdin driven day binaries (project per day, workspace, bin per day) as
+// processes. The binaries are found by building them with cargo, their input.txt is
+// expected next to their main.rs and their answers are read from the json records
+// of the day binary command line in common::cli.
+
+pub struct DayBinary {
+    pub executable: PathBuf,
+    // directory of the binary's main.rs containing its input.txt
+    pub source_dir: PathBuf,
+}
+
+pub struct Orchestrator {
+    days: BTreeMap<u8, DayBinary>,
+    check: bool,
+}
+
+// the command line of the programs running the day binaries, e.g. `--check day 5`
+#[derive(Debug, StructOpt)]
+pub struct Args {
+    /// has the day binaries check their input assumptions (common::assume!) and reports
+    /// violations
+    #[structopt(long)]
+    pub check: bool,
+    #[structopt(subcommand)]
+    pub opt: Opt,
+}
+
+// the answer of a part and the assumptions it violated (only checked with --check)
+pub struct PartRecord {
+    pub answer: Result<Answer>,
+    pub diagnostics: Vec<String>,
+}
+
+impl Orchestrator {
+    // root is either a package or workspace (built at once) or a directory
+    // containing one package per day (each built on its own)
+    pub fn build(root: &Path) -> Result<Orchestrator> {
+        let mut days = BTreeMap::new();
+        if root.join("Cargo.toml").is_file() {
+            days.append(&mut build_days(root)?);
+        } else {
+            let mut packages = Vec::new();
+            for entry in fs::read_dir(root)? {
+                let path = entry?.path();
+                if path.join("Cargo.toml").is_file() {
+                    packages.push(path);
+                }
+            }
+            packages.sort();
+            for package in packages {
+                days.append(&mut build_days(&package)?);
+            }
+        }
+        Ok(Orchestrator { days, check: false })
+    }
+    pub fn days(&self) -> &BTreeMap<u8, DayBinary> {
+        &self.days
+    }
+    // like Solver::run
+    pub fn run(&mut self, args: Args) -> ExitCode {
+        self.check = args.check;
+        self.solve(args.opt)
+    }
+    // like Solver::solve, fails if a compare found a difference
+    pub fn solve(&self, opt: Opt) -> ExitCode {
+        match opt {
+            Opt::All => self.solve_all(),
+            Opt::Day { day, part } => self.solve_day(day, part),
+            Opt::File { day, part, file } => self.solve_day_parts_file(day, &[part], &file),
+            Opt::Compare {
+                day,
+                file,
+                timeout,
+                command,
+            } => {
+                let timeout = Duration::from_secs(timeout);
+                return compare::exit_code(self.compare_day(day, file, &command, timeout));
+            }
+        }
+        ExitCode::SUCCESS
+    }
+    fn solve_all(&self) {
+        for day in self.days.keys() {
+            self.solve_day(*day, None);
+        }
+    }
+    fn solve_day(&self, day: u8, part: Option<u8>) {
+        let parts = match part {
+            Some(part) => vec![part],
+            None => vec![1, 2],
+        };
+        match self.days.get(&day) {
+            Some(binary) => {
+                self.solve_day_parts_file(day, &parts, &binary.source_dir.join("input.txt"))
+            }
+            None => {
+                for part in parts {
+                    print_part_prefix(day, part);
+                    print_part_result(None);
+                }
+            }
+        }
+    }
+    fn compare_day(
+        &self,
+        day: u8,
+        file: Option<PathBuf>,
+        command: &[String],
+        timeout: Duration,
+    ) -> bool {
+        let Some(binary) = self.days.get(&day) else {
+            self.solve_day(day, None);
+            return false;
+        };
+        let file = file.unwrap_or_else(|| binary.source_dir.join("input.txt"));
+        let content = match fs::read_to_string(&file) {
+            Ok(content) => content,
+            Err(err) => {
+                println!("Error reading {}: {}", file.display(), err);
+                return false;
+            }
+        };
+        let mut records = run_day(binary, &file, None, self.check);
+        let ours = |part| Some(take_answer(&mut records, part).answer);
+        compare(day, ours, &content, command, timeout)
+    }
+    fn solve_day_parts_file(&self, day: u8, parts: &[u8], file: &Path) {
+        if let Err(err) = fs::metadata(file) {
+            println!("Error reading {}: {}", file.display(), err);
+            return;
+        }
+        let part = match parts {
+            [part] => Some(*part),
+            _ => None,
+        };
+        let mut records = self
+            .days
+            .get(&day)
+            .map(|binary| run_day(binary, file, part, self.check));
+        for &part in parts {
+            print_part_prefix(day, part);
+            let Some(records) = records.as_mut() else {
+                print_part_result(None);
+                continue;
+            };
+            let record = take_answer(records, part);
+            print_part_result(Some(record.answer));
+            for diagnostic in record.diagnostics {
+                println!("    assumption violated: {}", diagnostic);
+            }
+        }
+    }
+}
+
+// the record of the part out of the results of run_day
+fn take_answer(records: &mut Result<BTreeMap<u8, PartRecord>>, part: u8) -> PartRecord {
+    let answer = match records {
+        Ok(records) => match records.remove(&part) {
+            Some(record) => return record,
+            None => Err(anyhow!("no record for part{} in the output", part)),
+        },
+        Err(err) => Err(anyhow!("{}", err)),
+    };
+    PartRecord {
+        answer,
+        diagnostics: Vec::new(),
+    }
+}
+
+// builds all binaries of the package or workspace, keeping those named dayNN
+fn build_days(manifest_dir: &Path) -> Result<BTreeMap<u8, DayBinary>> {
+    let cargo = env::var("CARGO").unwrap_or(String::from("cargo"));
+    let mut command = Command::new(cargo);
+    command
+        .args(["build", "--workspace", "--bins"])
+        .args(["--message-format", "json-render-diagnostics"])
+        .current_dir(manifest_dir)
+        .stderr(Stdio::inherit());
+    if !cfg!(debug_assertions) {
+        command.arg("--release");
+    }
+    let output = command.output()?;
+    if !output.status.success() {
+        return Err(anyhow!(
+            "building {} failed: {}",
+            manifest_dir.display(),
+            output.status
+        ));
+    }
+    let mut days = BTreeMap::new();
+    for line in String::from_utf8_lossy(&output.stdout).lines() {
+        let Ok(message) = serde_json::from_str::<serde_json::Value>(line) else {
+            continue;
+        };
+        if message["reason"] != "compiler-artifact" {
+            continue;
+        }
+        let (Some(executable), Some(name), Some(src_path)) = (
+            message["executable"].as_str(),
+            message["target"]["name"].as_str(),
+            message["target"]["src_path"].as_str(),
+        ) else {
+            continue;
+        };
+        if let Some(day) = day_number(name) {
+            let source_dir = Path::new(src_path)
+                .parent()
+                .ok_or(anyhow!("{} has no parent directory", src_path))?;
+            days.insert(
+                day,
+                DayBinary {
+                    executable: PathBuf::from(executable),
+                    source_dir: source_dir.to_path_buf(),
+                },
+            );
+        }
+    }
+    Ok(days)
+}
+
+fn day_number(name: &str) -> Option<u8> {
+    let number = name.strip_prefix("day")?;
+    if number.len() == 2 && number.chars().all(|c| c.is_ascii_digit()) {
+        number.parse().ok()
+    } else {
+        None
+    }
+}
+
+// runs the binary with `--format json` (see common::cli) and returns the result of each part
+pub fn run_day(
+    binary: &DayBinary,
+    input_file: &Path,
+    part: Option<u8>,
+    check: bool,
+) -> Result<BTreeMap<u8, PartRecord>> {
+    let mut command = Command::new(&binary.executable);
+    command
+        .arg("--input")
+        .arg(input_file)
+        .args(["--format", "json"])
+        .stdin(Stdio::null());
+    if let Some(part) = part {
+        command.args(["--part", &part.to_string()]);
+    }
+    if check {
+        command.arg("--check");
+    }
+    let output = command.output()?;
+    let failed_parts = [Some(EXIT_PARSE as i32), Some(EXIT_SOLVE as i32)];
+    if !output.status.success() && !failed_parts.contains(&output.status.code()) {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        // skip the hints following a panic message
+        let message = stderr
+            .lines()
+            .rfind(|l| !l.trim().is_empty() && !l.starts_with("note:"));
+        return Err(anyhow!(
+            "{} exited with {}: {}",
+            binary.executable.display(),
+            output.status,
+            message.unwrap_or("")
+        ));
+    }
+    parse_records(&String::from_utf8_lossy(&output.stdout))
+        .map_err(|err| anyhow!("{}: {}", binary.executable.display(), err))
+}
+
+// one json record per line, anything else on stdout (e.g. a day printing while it
+// solves) is an error as it breaks the json format
+pub fn parse_records(stdout: &str) -> Result<BTreeMap<u8, PartRecord>> {
+    stdout
+        .lines()
+        .map(|line| {
+            let record: serde_json::Value = serde_json::from_str(line)
+                .map_err(|_| anyhow!("not a json record on stdout: {:?}", line))?;
+            let part = record["part"]
+                .as_u64()
+                .and_then(|part| part.try_into().ok())
+                .ok_or_else(|| anyhow!("no part in {:?}", line))?;
+            let answer = match (record["answer"].as_str(), &record["error"]) {
+                (Some(answer), _) => Ok(answer.parse().unwrap()),
+                (None, error) => Err(anyhow!(
+                    "{} error: {}",
+                    error["kind"].as_str().unwrap_or("unknown"),
+                    error["message"].as_str().unwrap_or("")
+                )),
+            };
+            let diagnostics = record["diagnostics"]
+                .as_array()
+                .map_or(Vec::new(), |all| all.iter().map(diagnostic).collect());
+            Ok((
+                part,
+                PartRecord {
+                    answer,
+                    diagnostics,
+                },
+            ))
+        })
+        .collect()
+}
+
+// shown like a common::check::Diagnostic
+fn diagnostic(value: &serde_json::Value) -> String {
+    let text = |key: &str| value[key].as_str().unwrap_or("");
+    let mut shown = format!(
+        "{} ({}:{})",
+        text("assumption"),
+        text("file"),
+        value["line"]
+    );
+    if !text("detail").is_empty() {
+        shown = format!("{}: {}", shown, text("detail"));
+    }
+    shown
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn json_records() {
+        let stdout = "\
+{\"day\":1,\"part\":1,\"answer\":\"54450\",\"parse_ns\":1,\"solve_ns\":2,\"error\":null}
+{\"day\":1,\"part\":2,\"answer\":null,\"parse_ns\":1,\"solve_ns\":2,\"error\":{\"kind\":\"solve\",\"message\":\"oops\"},\"diagnostics\":[{\"assumption\":\"short lines\",\"detail\":\"line 3\",\"file\":\"src/day01/mod.rs\",\"line\":12}]}
+";
+        let records = parse_records(stdout).unwrap();
+        assert_eq!(records.len(), 2);
+        assert_eq!(records[&1].answer.as_ref().unwrap(), &Answer::Num(54450));
+        assert!(records[&1].diagnostics.is_empty());
+        assert_eq!(
+            records[&2].diagnostics,
+            ["short lines (src/day01/mod.rs:12): line 3"]
+        );
+        assert_eq!(
+            records[&2].answer.as_ref().unwrap_err().to_string(),
+            "solve error: oops"
+        );
+        let printed = parse_records("16\n{\"day\":4,\"part\":1,\"answer\":\"16\"}\n");
+        assert!(printed.err().unwrap().to_string().contains("\"16\""));
+    }
+    #[test]
+    fn day_numbers() {
+        assert_eq!(day_number("day03"), Some(3));
+        assert_eq!(day_number("day25"), Some(25));
+        assert_eq!(day_number("day3"), None);
+        assert_eq!(day_number("runner"), None);
+    }
+}
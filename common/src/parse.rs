@@ -0,0 +1,331 @@
+use anyhow::{anyhow, Result};
+use std::{any::type_name, iter::FusedIterator, ops::Range};
+
+// Parsing straight from the bytes of the input, without regexes or allocations:
+//
+//     let numbers: Vec<u64> = parse::integers(line).collect();   // "seeds: 79 14 55 13"
+//     for block in parse::blocks(input) { .. }                    // blocks between empty lines
+//     let row: Vec<u8> = parse::fixed(b"41 48  6", 3).collect();  // right aligned columns
+//
+// Everything takes &[u8] or &str. Numbers that don't fit the type panic, like an
+// overflowing addition in a debug build, try_integers and try_fixed return an error
+// for them instead:
+//
+//     let numbers: Vec<u8> = parse::try_integers(line).collect::<Result<_>>()?;
+
+pub trait Integer: Copy {
+    const SIGNED: bool;
+    const ZERO: Self;
+    // value * 10 + digit (or - digit), None on overflow
+    fn push_digit(self, digit: u8, negative: bool) -> Option<Self>;
+}
+
+macro_rules! integer {
+    ($($t:ty)*) => {$(
+        impl Integer for $t {
+            const SIGNED: bool = <$t>::MIN != 0;
+            const ZERO: $t = 0;
+            #[inline]
+            fn push_digit(self, digit: u8, negative: bool) -> Option<$t> {
+                let value = self.checked_mul(10)?;
+                // subtracting reaches MIN as well
+                if negative {
+                    value.checked_sub(digit as $t)
+                } else {
+                    value.checked_add(digit as $t)
+                }
+            }
+        }
+    )*};
+}
+
+integer!(i8 i16 i32 i64 i128 isize u8 u16 u32 u64 u128 usize);
+
+// a whole field like " -42", None if it isn't a single number
+pub fn integer<T: Integer>(field: &(impl AsRef<[u8]> + ?Sized)) -> Option<T> {
+    let field = field.as_ref().trim_ascii();
+    let (negative, digits) = match field {
+        [b'-', digits @ ..] if T::SIGNED => (true, digits),
+        [b'+', digits @ ..] => (false, digits),
+        digits => (false, digits),
+    };
+    if digits.is_empty() {
+        return None;
+    }
+    digits.iter().try_fold(T::ZERO, |value, &byte| {
+        byte.is_ascii_digit()
+            .then(|| value.push_digit(byte - b'0', negative))?
+    })
+}
+
+// all numbers in the input, a '-' right before the digits makes them negative for signed
+// types (so "3-4" is 3, -4 for i32 and 3, 4 for u32)
+pub fn integers<T: Integer>(input: &(impl AsRef<[u8]> + ?Sized)) -> Integers<'_, T> {
+    Integers {
+        input: input.as_ref(),
+        position: 0,
+        marker: std::marker::PhantomData,
+    }
+}
+
+pub struct Integers<'a, T> {
+    input: &'a [u8],
+    position: usize,
+    marker: std::marker::PhantomData<T>,
+}
+
+impl<'a, T: Integer> Integers<'a, T> {
+    // the numbers with their byte range in the input, the '-' included
+    pub fn spanned(self) -> Spanned<'a, T> {
+        Spanned(self)
+    }
+
+    fn next_spanned(&mut self) -> Option<(T, Range<usize>)> {
+        self.try_next_spanned()
+            .map(|number| number.unwrap_or_else(|err| panic!("parse: {}", err)))
+    }
+
+    fn try_next_spanned(&mut self) -> Option<Result<(T, Range<usize>)>> {
+        let input = self.input;
+        let mut position = self.position;
+        while position < input.len() && !input[position].is_ascii_digit() {
+            position += 1;
+        }
+        if position == input.len() {
+            self.position = position;
+            return None;
+        }
+        let start = position;
+        let negative = T::SIGNED && start > 0 && input[start - 1] == b'-';
+        let mut value = Some(T::ZERO);
+        while position < input.len() && input[position].is_ascii_digit() {
+            value = value.and_then(|value| value.push_digit(input[position] - b'0', negative));
+            position += 1;
+        }
+        self.position = position;
+        let Some(value) = value else {
+            return Some(Err(anyhow!(
+                "{}{} doesn't fit into {}",
+                if negative { "-" } else { "" },
+                String::from_utf8_lossy(&input[start..position]),
+                type_name::<T>()
+            )));
+        };
+        Some(Ok((value, start - negative as usize..position)))
+    }
+}
+
+impl<T: Integer> Iterator for Integers<'_, T> {
+    type Item = T;
+    fn next(&mut self) -> Option<T> {
+        self.next_spanned().map(|(value, _)| value)
+    }
+}
+
+impl<T: Integer> FusedIterator for Integers<'_, T> {}
+
+pub struct Spanned<'a, T>(Integers<'a, T>);
+
+impl<T: Integer> Iterator for Spanned<'_, T> {
+    type Item = (T, Range<usize>);
+    fn next(&mut self) -> Option<(T, Range<usize>)> {
+        self.0.next_spanned()
+    }
+}
+
+impl<T: Integer> FusedIterator for Spanned<'_, T> {}
+
+// like integers, but a number that doesn't fit the type is an error instead of a panic
+pub fn try_integers<T: Integer>(input: &(impl AsRef<[u8]> + ?Sized)) -> TryIntegers<'_, T> {
+    TryIntegers(integers(input))
+}
+
+pub struct TryIntegers<'a, T>(Integers<'a, T>);
+
+impl<'a, T: Integer> TryIntegers<'a, T> {
+    // the numbers with their byte range in the input, see Integers::spanned
+    pub fn spanned(self) -> TrySpanned<'a, T> {
+        TrySpanned(self.0)
+    }
+}
+
+impl<T: Integer> Iterator for TryIntegers<'_, T> {
+    type Item = Result<T>;
+    fn next(&mut self) -> Option<Result<T>> {
+        self.0
+            .try_next_spanned()
+            .map(|number| number.map(|(value, _)| value))
+    }
+}
+
+impl<T: Integer> FusedIterator for TryIntegers<'_, T> {}
+
+pub struct TrySpanned<'a, T>(Integers<'a, T>);
+
+impl<T: Integer> Iterator for TrySpanned<'_, T> {
+    type Item = Result<(T, Range<usize>)>;
+    fn next(&mut self) -> Option<Result<(T, Range<usize>)>> {
+        self.0.try_next_spanned()
+    }
+}
+
+impl<T: Integer> FusedIterator for TrySpanned<'_, T> {}
+
+// fields of `width` bytes (the last one may be shorter) holding a number each, padded
+// with spaces, e.g. width 3 for "41 48  6" where the separator belongs to the field
+pub fn fixed<T: Integer>(
+    line: &(impl AsRef<[u8]> + ?Sized),
+    width: usize,
+) -> impl Iterator<Item = T> + '_ {
+    try_fixed(line, width).map(|field| field.unwrap_or_else(|err| panic!("parse: {}", err)))
+}
+
+// like fixed, but a field that isn't a number of the type is an error instead of a panic
+pub fn try_fixed<T: Integer>(
+    line: &(impl AsRef<[u8]> + ?Sized),
+    width: usize,
+) -> impl Iterator<Item = Result<T>> + '_ {
+    assert!(width > 0, "parse: fields need a width");
+    line.as_ref().chunks(width).map(|field| {
+        integer(field).ok_or_else(|| {
+            anyhow!(
+                "{:?} is no {}",
+                String::from_utf8_lossy(field),
+                type_name::<T>()
+            )
+        })
+    })
+}
+
+// like str::lines: without the trailing "\n" or "\r\n" and no empty last line
+pub fn lines(input: &(impl AsRef<[u8]> + ?Sized)) -> impl Iterator<Item = &[u8]> {
+    let input = input.as_ref();
+    let empty = input.is_empty();
+    let input = input.strip_suffix(b"\n").unwrap_or(input);
+    input
+        .split(|&byte| byte == b'\n')
+        .filter(move |_| !empty)
+        .map(|line| line.strip_suffix(b"\r").unwrap_or(line))
+}
+
+// groups of lines separated by empty lines, the lines of a block keep their newlines
+pub fn blocks(input: &(impl AsRef<[u8]> + ?Sized)) -> Blocks<'_> {
+    Blocks {
+        input: input.as_ref(),
+    }
+}
+
+pub struct Blocks<'a> {
+    input: &'a [u8],
+}
+
+impl<'a> Iterator for Blocks<'a> {
+    type Item = &'a [u8];
+    fn next(&mut self) -> Option<&'a [u8]> {
+        // leading empty lines separate as well
+        while let Some(rest) = self
+            .input
+            .strip_prefix(b"\n")
+            .or_else(|| self.input.strip_prefix(b"\r\n"))
+        {
+            self.input = rest;
+        }
+        if self.input.is_empty() {
+            return None;
+        }
+        let mut end = 0;
+        while end < self.input.len() {
+            let line_end = self.input[end..]
+                .iter()
+                .position(|&byte| byte == b'\n')
+                .map_or(self.input.len(), |i| end + i + 1);
+            let line = &self.input[end..line_end];
+            if line == b"\n" || line == b"\r\n" {
+                break;
+            }
+            end = line_end;
+        }
+        let block = &self.input[..end];
+        self.input = &self.input[end..];
+        Some(block)
+    }
+}
+
+impl FusedIterator for Blocks<'_> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn numbers() {
+        let line = "Card  12: x-3 +4 -5-6 170";
+        assert_eq!(
+            integers::<i32>(line).collect::<Vec<_>>(),
+            [12, -3, 4, -5, -6, 170]
+        );
+        assert_eq!(
+            integers::<u8>(line).collect::<Vec<_>>(),
+            [12, 3, 4, 5, 6, 170]
+        );
+        let spans: Vec<_> = integers::<i64>("..-35..633").spanned().collect();
+        assert_eq!(spans, [(-35, 2..5), (633, 7..10)]);
+        assert_eq!(integers::<u64>(b"no numbers".as_slice()).count(), 0);
+        assert_eq!(integers::<i8>("-128 127").collect::<Vec<_>>(), [-128, 127]);
+        assert_eq!(integer::<i64>(" -42 "), Some(-42));
+        assert_eq!(integer::<u64>("-42"), None);
+        assert_eq!(integer::<u64>("4 2"), None);
+        assert_eq!(integer::<u64>(""), None);
+        assert_eq!(integer::<u8>("256"), None);
+    }
+    #[test]
+    #[should_panic(expected = "parse: 300 doesn't fit into u8")]
+    fn overflow() {
+        integers::<u8>("1 300").for_each(drop);
+    }
+    #[test]
+    fn overflow_error() {
+        let numbers: Vec<_> = try_integers::<u8>("1 300 2").map(|n| n.ok()).collect();
+        assert_eq!(numbers, [Some(1), None, Some(2)]);
+        let err = try_integers::<i8>("-129").next().unwrap().unwrap_err();
+        assert_eq!(err.to_string(), "-129 doesn't fit into i8");
+        assert!(try_integers::<u64>("1 2 3").all(|n| n.is_ok()));
+        let spans: Vec<_> = try_integers::<i8>("5 -200").spanned().collect();
+        assert_eq!(spans[0].as_ref().unwrap(), &(5, 0..1));
+        assert!(spans[1].is_err());
+    }
+    #[test]
+    fn fixed_width() {
+        assert_eq!(fixed::<u8>("41 48  6", 3).collect::<Vec<_>>(), [41, 48, 6]);
+        assert_eq!(fixed::<i16>(" -1 12", 3).collect::<Vec<_>>(), [-1, 12]);
+    }
+    #[test]
+    fn fixed_width_error() {
+        let fields: Vec<_> = try_fixed::<u8>("41 4x  6", 3).map(|n| n.ok()).collect();
+        assert_eq!(fields, [Some(41), None, Some(6)]);
+        let err = try_fixed::<u8>("300", 3).next().unwrap().unwrap_err();
+        assert_eq!(err.to_string(), "\"300\" is no u8");
+    }
+    #[test]
+    #[should_panic(expected = "parse: \"4x \" is no u8")]
+    fn fixed_panics() {
+        fixed::<u8>("41 4x  6", 3).for_each(drop);
+    }
+    #[test]
+    fn lines_and_blocks() {
+        let input = "seeds: 1 2\r\n\r\nmap:\r\n1 2 3\r\n4 5 6\r\n\r\n\r\nmap:\r\n7 8 9\r\n";
+        let lines: Vec<&[u8]> = lines(input).collect();
+        assert_eq!(lines.len(), 9);
+        assert_eq!(lines[0], b"seeds: 1 2");
+        assert_eq!(lines[1], b"");
+        let blocks: Vec<&[u8]> = blocks(input).collect();
+        assert_eq!(blocks.len(), 3);
+        assert_eq!(blocks[1], b"map:\r\n1 2 3\r\n4 5 6\r\n");
+        assert_eq!(blocks[2], b"map:\r\n7 8 9\r\n");
+        assert_eq!(self::lines("").count(), 0);
+        assert_eq!(self::lines("a\n\nb").count(), 3);
+        assert_eq!(self::lines("\n").collect::<Vec<_>>(), [b""]);
+        assert_eq!(self::blocks("\n\n").count(), 0);
+        assert_eq!(self::blocks("a\nb").collect::<Vec<_>>(), [b"a\nb"]);
+    }
+}
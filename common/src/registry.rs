@@ -0,0 +1,43 @@
+use crate::{input::InputFile, Answer, SolutionFn};
+use anyhow::Result;
+
+// The parts registered with #[aoc(day = N, part = M)] (see aoc_macros), collected at
+// link time. Solver::add_registered adds all of them.
+
+pub use aoc_macros::{aoc, aoc_parser};
+pub use inventory;
+
+pub struct Registration {
+    pub day: u8,
+    pub part: u8,
+    pub fun: fn(&str) -> Result<Answer>,
+}
+
+// a part taking &InputFile, which the Solver prefers over the one taking the text
+pub struct SourceRegistration {
+    pub day: u8,
+    pub part: u8,
+    pub fun: fn(&InputFile) -> Result<Answer>,
+}
+
+// a day implementing Solution, #[aoc(day = N)] on the impl
+pub struct SolutionRegistration {
+    pub day: u8,
+    pub fun: SolutionFn,
+}
+
+inventory::collect!(Registration);
+inventory::collect!(SourceRegistration);
+inventory::collect!(SolutionRegistration);
+
+pub fn registered() -> impl Iterator<Item = &'static Registration> {
+    inventory::iter::<Registration>.into_iter()
+}
+
+pub fn registered_sources() -> impl Iterator<Item = &'static SourceRegistration> {
+    inventory::iter::<SourceRegistration>.into_iter()
+}
+
+pub fn registered_solutions() -> impl Iterator<Item = &'static SolutionRegistration> {
+    inventory::iter::<SolutionRegistration>.into_iter()
+}
@@ -0,0 +1,219 @@
+use anyhow::{anyhow, Result};
+use std::{any::type_name, fmt::Display, ops::Deref, str::FromStr};
+
+// Parsing lines with a format string instead of a regex:
+//
+//     let (id, winners, numbers) = scan!("Card {}: {} | {}", line => usize, Words<usize>, Words<usize>)?;
+//
+// Every {} captures the text up to the next literal part of the pattern (the last {}
+// up to the end of the input), the captures are trimmed and parsed with FromStr into a
+// tuple. The number of {} and types is checked at compile time.
+
+#[macro_export]
+macro_rules! scan {
+    ($pattern:literal, $input:expr => $($t:ty),+ $(,)?) => {{
+        const _: () = assert!(
+            $crate::scan::placeholders($pattern) == [$(stringify!($t)),+].len(),
+            "scan!: the number of {{}} in the pattern and of types differ"
+        );
+        let input: &str = &$input;
+        $crate::scan::captures($pattern, input).and_then(|captures| {
+            let mut captures = captures.into_iter().enumerate();
+            Ok(($({
+                let (field, capture) = captures.next().unwrap();
+                $crate::scan::parse::<$t>(input, field, capture)?
+            },)+))
+        })
+    }};
+}
+
+pub const fn placeholders(pattern: &str) -> usize {
+    let bytes = pattern.as_bytes();
+    let mut count = 0;
+    let mut i = 0;
+    while i + 1 < bytes.len() {
+        if bytes[i] == b'{' && bytes[i + 1] == b'}' {
+            count += 1;
+            i += 2;
+        } else {
+            i += 1;
+        }
+    }
+    count
+}
+
+pub fn captures<'a>(pattern: &str, input: &'a str) -> Result<Vec<&'a str>> {
+    let mismatch = |literal: &str| {
+        anyhow!(
+            "scan!: {:?} doesn't match {:?}, {:?} not found",
+            input,
+            pattern,
+            literal
+        )
+    };
+    let mut literals = pattern.split("{}");
+    let first = literals.next().unwrap_or("");
+    let mut rest = input.strip_prefix(first).ok_or_else(|| mismatch(first))?;
+    let literals: Vec<&str> = literals.collect();
+    let mut captures = Vec::new();
+    for (i, literal) in literals.iter().enumerate() {
+        let capture = if i + 1 == literals.len() {
+            let capture = rest
+                .strip_suffix(literal)
+                .ok_or_else(|| mismatch(literal))?;
+            rest = "";
+            capture
+        } else if literal.is_empty() {
+            return Err(anyhow!("scan!: {:?} has adjacent {{}}", pattern));
+        } else {
+            let end = rest.find(literal).ok_or_else(|| mismatch(literal))?;
+            let capture = &rest[..end];
+            rest = &rest[end + literal.len()..];
+            capture
+        };
+        captures.push(capture.trim());
+    }
+    Ok(captures)
+}
+
+pub fn parse<T>(input: &str, field: usize, capture: &str) -> Result<T>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    capture.parse().map_err(|err| {
+        anyhow!(
+            "scan!: field {} {:?} of {:?} is no {}: {}",
+            field + 1,
+            capture,
+            input,
+            type_name::<T>(),
+            err
+        )
+    })
+}
+
+// the fields of #[derive(FromLine)] (see aoc_macros)
+pub fn field<T>(line: &str, name: &str, capture: &str) -> Result<T>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    let capture = capture.trim();
+    capture.parse().map_err(|err| {
+        anyhow!(
+            "field `{}` {:?} of {:?} is no {}: {}",
+            name,
+            capture,
+            line,
+            type_name::<T>(),
+            err
+        )
+    })
+}
+
+// Vec fields, split at whitespace without a separator
+pub fn items<T>(line: &str, name: &str, capture: &str, sep: Option<&str>) -> Result<Vec<T>>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    let capture = capture.trim();
+    let items: Vec<&str> = match sep {
+        _ if capture.is_empty() => Vec::new(),
+        Some(sep) => capture.split(sep).map(str::trim).collect(),
+        None => capture.split_whitespace().collect(),
+    };
+    items
+        .into_iter()
+        .enumerate()
+        .map(|(i, item)| {
+            item.parse().map_err(|err| {
+                anyhow!(
+                    "field `{}` item {} {:?} of {:?} is no {}: {}",
+                    name,
+                    i + 1,
+                    item,
+                    line,
+                    type_name::<T>(),
+                    err
+                )
+            })
+        })
+        .collect()
+}
+
+// whitespace separated values, e.g. the numbers of a card
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Words<T>(pub Vec<T>);
+
+impl<T> FromStr for Words<T>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    type Err = anyhow::Error;
+    fn from_str(s: &str) -> Result<Self> {
+        s.split_whitespace()
+            .map(|word| word.parse().map_err(|err| anyhow!("{:?}: {}", word, err)))
+            .collect::<Result<_>>()
+            .map(Words)
+    }
+}
+
+impl<T> Deref for Words<T> {
+    type Target = Vec<T>;
+    fn deref(&self) -> &Vec<T> {
+        &self.0
+    }
+}
+
+impl<T> IntoIterator for Words<T> {
+    type Item = T;
+    type IntoIter = std::vec::IntoIter<T>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn tuples() -> Result<()> {
+        let line = "Card   1: 41 48 83 | 83 86  6 31";
+        let (id, winners, numbers) =
+            scan!("Card {}: {} | {}", line => usize, Words<u8>, Words<u8>)?;
+        assert_eq!(id, 1);
+        assert_eq!(*winners, [41, 48, 83]);
+        assert_eq!(numbers.iter().filter(|n| winners.contains(n)).count(), 1);
+        let (dst, src, len) = scan!("{} {} {}", String::from("50 98 2") => u64, u64, u64)?;
+        assert_eq!((dst, src, len), (50, 98, 2));
+        let (color,) = scan!("3 {}", "3 blue" => String)?;
+        assert_eq!(color, "blue");
+        let (game, sets) = scan!("Game {}: {};", "Game 2: 1 red; 2 blue;" => u32, String)?;
+        assert_eq!((game, sets.as_str()), (2, "1 red; 2 blue"));
+        Ok(())
+    }
+    #[test]
+    fn errors() {
+        let err = scan!("Card {}: {}", "Card x: 1" => usize, Words<u8>).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "scan!: field 1 \"x\" of \"Card x: 1\" is no usize: invalid digit found in string"
+        );
+        let err = scan!("Card {}: {}", "Card 1 1 2" => usize, Words<u8>).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "scan!: \"Card 1 1 2\" doesn't match \"Card {}: {}\", \": \" not found"
+        );
+        let err = scan!("{}: {}", "1: 2 z" => u8, Words<u8>).unwrap_err();
+        assert!(err
+            .to_string()
+            .ends_with("is no common::scan::Words<u8>: \"z\": invalid digit found in string"));
+        let err = scan!("{}{}", "12" => u8, u8).unwrap_err();
+        assert_eq!(err.to_string(), "scan!: \"{}{}\" has adjacent {}");
+        assert_eq!(placeholders("Game {}: {} {x}"), 2);
+    }
+}
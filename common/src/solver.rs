@@ -0,0 +1,438 @@
+use crate::{
+    cache::{self, Cache},
+    check,
+    compare::{self, compare},
+    input::InputFile,
+    solution_parts, trace, Answer, Solution, SolutionFn,
+};
+use anyhow::Result;
+use std::{collections::HashMap, path::PathBuf, process::ExitCode, time::Duration};
+pub use structopt::StructOpt;
+
+#[derive(Debug, StructOpt)]
+pub enum Opt {
+    All,
+    Day {
+        day: u8,
+        part: Option<u8>,
+    },
+    File {
+        day: u8,
+        part: u8,
+        file: PathBuf,
+    },
+    /// runs another program on the day's input and compares its answers with ours,
+    /// e.g. `compare 5 -- python3 day05.py`
+    Compare {
+        day: u8,
+        /// input file to use instead of the day's input.txt
+        #[structopt(long)]
+        file: Option<PathBuf>,
+        /// seconds after which the program is killed
+        #[structopt(long, default_value = "10")]
+        timeout: u64,
+        /// the program and its arguments, it reads the input from stdin and prints
+        /// `part1: <answer>` and `part2: <answer>` lines
+        #[structopt(required = true)]
+        command: Vec<String>,
+    },
+}
+
+// the command line of the overarching programs, e.g. `--trace out.json --check day 5`
+#[derive(Debug, StructOpt)]
+pub struct Args {
+    /// writes the parse and part spans to this file (Chrome trace-event format)
+    #[structopt(long)]
+    pub trace: Option<PathBuf>,
+    /// checks the input assumptions of the days (common::assume!) and reports violations
+    #[structopt(long)]
+    pub check: bool,
+    /// reads the inputs from disk although they are embedded (embed-inputs feature)
+    #[structopt(long)]
+    pub from_disk: bool,
+    /// solves every part although its answer is cached
+    #[structopt(long)]
+    pub no_cache: bool,
+    #[structopt(subcommand)]
+    pub opt: Opt,
+}
+
+pub type PartFn = fn(&str) -> Result<Answer>;
+pub type SourceFn = fn(&InputFile) -> Result<Answer>;
+
+pub struct Solver {
+    solutions: HashMap<(u8, u8), PartFn>,
+    sources: HashMap<(u8, u8), SourceFn>,
+    // days implementing Solution, by day
+    typed: HashMap<u8, SolutionFn>,
+    input_file: fn(u8) -> PathBuf,
+    // by day and file name, e.g. (3, "test.txt")
+    embedded: HashMap<(u8, String), &'static str>,
+    from_disk: bool,
+    cache: Option<Cache>,
+}
+
+impl Default for Solver {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Solver {
+    // inputs are read from src/dayNN/input.txt like in the module per day layout
+    pub fn new() -> Solver {
+        Solver::with_input_file(|day| PathBuf::from(format!("src/day{:02}/input.txt", day)))
+    }
+    pub fn with_input_file(input_file: fn(u8) -> PathBuf) -> Solver {
+        Solver {
+            solutions: HashMap::new(),
+            sources: HashMap::new(),
+            typed: HashMap::new(),
+            input_file,
+            embedded: HashMap::new(),
+            from_disk: false,
+            cache: None,
+        }
+    }
+    pub fn add(&mut self, day: u8, part: u8, fun: PartFn) {
+        self.solutions.insert((day, part), fun);
+    }
+    // preferred over a part added with add, the file isn't read into a String for it
+    pub fn add_source(&mut self, day: u8, part: u8, fun: SourceFn) {
+        self.sources.insert((day, part), fun);
+    }
+    // both parts share one parse of the input, see parts
+    pub fn add_solution<S: Solution + 'static>(&mut self, day: u8) {
+        self.typed.insert(day, solution_parts::<S>);
+    }
+    // a file compiled into the binary, used instead of the file with the same name in the
+    // day's directory (input.txt for the day and all subcommands)
+    pub fn embed(&mut self, day: u8, name: &str, text: &'static str) {
+        self.embedded.insert((day, String::from(name)), text);
+    }
+    // all parts marked with #[aoc(day = N, part = M)]
+    #[cfg(feature = "macros")]
+    pub fn add_registered(&mut self) {
+        for registration in crate::registry::registered() {
+            self.add(registration.day, registration.part, registration.fun);
+        }
+        for registration in crate::registry::registered_sources() {
+            self.add_source(registration.day, registration.part, registration.fun);
+        }
+        for registration in crate::registry::registered_solutions() {
+            self.typed.insert(registration.day, registration.fun);
+        }
+    }
+    // fails if a compare found a difference, see solve
+    pub fn run(&mut self, args: Args) -> ExitCode {
+        self.from_disk = args.from_disk;
+        // traces and checks need the parts to run
+        if !args.no_cache && !args.check && args.trace.is_none() {
+            self.cache = Cache::open();
+        }
+        if args.trace.is_some() {
+            trace::start();
+        }
+        if args.check {
+            check::start();
+        }
+        let code = self.solve(args.opt);
+        if let Some(file) = args.trace {
+            match trace::write(&file) {
+                Ok(()) => println!("trace written to {}", file.display()),
+                Err(err) => println!("Error writing {}: {}", file.display(), err),
+            }
+        }
+        code
+    }
+    // failing parts are only printed, the exit code tells whether a compare matched
+    pub fn solve(&self, opt: Opt) -> ExitCode {
+        match opt {
+            Opt::All => self.solve_all(),
+            Opt::Day { day, part } => self.solve_day(day, part),
+            Opt::File { day, part, file } => self.solve_day_part_file(day, part, file),
+            Opt::Compare {
+                day,
+                file,
+                timeout,
+                command,
+            } => {
+                let input = match file {
+                    Some(file) => self.file_input(day, file),
+                    None => self.day_input(day),
+                };
+                let all_match = match input.text() {
+                    Ok(content) => {
+                        let ours = self.parts(day, &input);
+                        compare(day, ours, content, &command, Duration::from_secs(timeout))
+                    }
+                    Err(err) => {
+                        println!("Error reading {}: {}", input.path().display(), err);
+                        false
+                    }
+                };
+                return compare::exit_code(all_match);
+            }
+        }
+        ExitCode::SUCCESS
+    }
+    fn solve_all(&self) {
+        for i in 1..=25 {
+            self.solve_day(i, None);
+        }
+    }
+    fn solve_day(&self, day: u8, part: Option<u8>) {
+        let input = self.day_input(day);
+        match part {
+            Some(part) => self.solve_parts(day, &[part], &input),
+            None => self.solve_parts(day, &[1, 2], &input),
+        }
+    }
+    fn solve_day_part_file(&self, day: u8, part: u8, file: PathBuf) {
+        self.solve_parts(day, &[part], &self.file_input(day, file));
+    }
+    fn day_input(&self, day: u8) -> InputFile {
+        let path = (self.input_file)(day);
+        match self.embedded_file(day, &path) {
+            Some(text) => InputFile::embedded(path, text),
+            None => InputFile::new(path),
+        }
+    }
+    // a path given on the command line, an embedded file of the same name if it doesn't
+    // exist (so `file 3 1 test.txt` works anywhere)
+    fn file_input(&self, day: u8, path: PathBuf) -> InputFile {
+        match self.embedded_file(day, &path) {
+            Some(text) if !path.exists() => InputFile::embedded(path, text),
+            _ => InputFile::new(path),
+        }
+    }
+    fn embedded_file(&self, day: u8, path: &std::path::Path) -> Option<&'static str> {
+        if self.from_disk {
+            return None;
+        }
+        let name = path.file_name()?.to_str()?;
+        self.embedded.get(&(day, String::from(name))).copied()
+    }
+    fn solve_parts(&self, day: u8, parts: &[u8], input: &InputFile) {
+        // parts taking the InputFile alone don't read it
+        let read = parts
+            .iter()
+            .any(|part| !self.sources.contains_key(&(day, *part)));
+        if read {
+            if let Err(err) = input.text() {
+                println!("Error reading {}: {}", input.path().display(), err);
+                return;
+            }
+        }
+        // hashed once for all parts, from the text if it was read anyway
+        let cache = self.cache.as_ref().and_then(|cache| {
+            let hash = if read {
+                cache::hash(input.text().ok()?.as_bytes())
+            } else {
+                cache::hash(&input.bytes().ok()?)
+            };
+            Some((cache, hash))
+        });
+        let mut solve = self.parts(day, input);
+        for &part in parts {
+            print_part_prefix(day, part);
+            if let Some(answer) = cache.and_then(|(cache, hash)| cache.get(day, part, hash)) {
+                // on stderr, the answer lines are the same as without a cache
+                println!("{}", answer);
+                eprintln!("    (cached)");
+                continue;
+            }
+            let result = {
+                let _span = trace::span(format!("day{:02} part{}", day, part));
+                solve(part)
+            };
+            if let (Some((cache, hash)), Some(Ok(answer))) = (cache, &result) {
+                cache.insert(day, part, hash, answer);
+            }
+            print_part_result(result);
+            for diagnostic in check::take() {
+                println!("    assumption violated: {}", diagnostic);
+            }
+        }
+    }
+    // solves the parts of the day for the input, None for a part that wasn't added
+    fn parts<'a>(
+        &'a self,
+        day: u8,
+        input: &'a InputFile,
+    ) -> impl FnMut(u8) -> Option<Result<Answer>> + 'a {
+        let mut typed = None;
+        move |part| {
+            if let Some(fun) = self.sources.get(&(day, part)) {
+                return Some(fun(input));
+            }
+            if let Some(parts) = self.typed.get(&day) {
+                let text = match input.text() {
+                    Ok(text) => text,
+                    Err(err) => return Some(Err(err.into())),
+                };
+                return Some(typed.get_or_insert_with(|| parts(text))(part));
+            }
+            let fun = self.solutions.get(&(day, part))?;
+            Some(input.text().map_err(Into::into).and_then(fun))
+        }
+    }
+}
+
+// shared with the other runners such that they all print the same
+pub(crate) fn print_part_prefix(day: u8, part: u8) {
+    print!("day{:02} part{:02}: ", day, part);
+}
+
+pub(crate) fn print_part_result(result: Option<Result<Answer>>) {
+    match result {
+        Some(Ok(answer)) => println!("{}", answer),
+        Some(Err(err)) => println!("Err: implementation failed with: {}", err),
+        None => println!("Err: No solution was added to solver!"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anyhow::anyhow;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    struct Numbers;
+
+    impl Solution for Numbers {
+        type Input<'a> = Vec<u64>;
+        fn parse(input: &str) -> Result<Vec<u64>> {
+            input
+                .split_whitespace()
+                .map(|n| n.parse().map_err(|_| anyhow!("not a number: {}", n)))
+                .collect()
+        }
+        fn part_one(input: &Self::Input<'_>) -> Result<impl Into<Answer>> {
+            Ok(input.iter().sum::<u64>())
+        }
+        fn part_two(input: &Self::Input<'_>) -> Result<impl Into<Answer>> {
+            Ok(format!("{} numbers", input.len()))
+        }
+    }
+
+    struct Letters;
+
+    impl Solution for Letters {
+        type Input<'a> = String;
+        fn parse(input: &str) -> Result<String> {
+            Ok(input.trim().to_uppercase())
+        }
+        fn part_one(input: &Self::Input<'_>) -> Result<impl Into<Answer>> {
+            Ok(input.len())
+        }
+        fn part_two(input: &Self::Input<'_>) -> Result<impl Into<Answer>> {
+            Ok(input.as_str())
+        }
+    }
+
+    // borrows the words from the input
+    struct Words;
+
+    impl Solution for Words {
+        type Input<'a> = Vec<&'a str>;
+        fn parse(input: &str) -> Result<Vec<&str>> {
+            Ok(input.split_whitespace().collect())
+        }
+        fn part_one(input: &Self::Input<'_>) -> Result<impl Into<Answer>> {
+            Ok(input.len())
+        }
+        fn part_two(input: &Self::Input<'_>) -> Result<impl Into<Answer>> {
+            input
+                .iter()
+                .max_by_key(|word| word.len())
+                .copied()
+                .ok_or_else(|| anyhow!("no words"))
+        }
+    }
+
+    fn solve(solver: &Solver, day: u8, part: u8, input: &'static str) -> Result<Answer> {
+        solver.parts(day, &InputFile::embedded("input.txt", input))(part).unwrap()
+    }
+
+    #[test]
+    fn typed_solutions() -> Result<()> {
+        let mut solver = Solver::new();
+        solver.add_solution::<Numbers>(1);
+        solver.add_solution::<Letters>(2);
+        assert_eq!(solve(&solver, 1, 1, "1 2 39")?, Answer::Num(42));
+        assert_eq!(solve(&solver, 1, 2, "1 2 39")?, Answer::from("3 numbers"));
+        assert_eq!(solve(&solver, 2, 1, "abc\n")?, Answer::Num(3));
+        assert_eq!(solve(&solver, 2, 2, "abc\n")?, Answer::from("ABC"));
+        solver.add_solution::<Words>(3);
+        assert_eq!(solve(&solver, 3, 1, "a bcd ef")?, Answer::Num(3));
+        assert_eq!(solve(&solver, 3, 2, "a bcd ef")?, Answer::from("bcd"));
+        let err = solve(&solver, 1, 1, "1 x").unwrap_err();
+        assert_eq!(err.to_string(), "not a number: x");
+        Ok(())
+    }
+    #[test]
+    fn parsed_once() -> Result<()> {
+        static PARSES: AtomicUsize = AtomicUsize::new(0);
+        struct Counted;
+        impl Solution for Counted {
+            type Input<'a> = ();
+            fn parse(_: &str) -> Result<()> {
+                PARSES.fetch_add(1, Ordering::Relaxed);
+                Ok(())
+            }
+            fn part_one(_: &Self::Input<'_>) -> Result<impl Into<Answer>> {
+                Ok(1)
+            }
+            fn part_two(_: &Self::Input<'_>) -> Result<impl Into<Answer>> {
+                Ok(2)
+            }
+        }
+        let mut solver = Solver::new();
+        solver.add_solution::<Counted>(1);
+        let input = InputFile::embedded("input.txt", "");
+        let mut parts = solver.parts(1, &input);
+        assert_eq!(parts(1).unwrap()?, Answer::Num(1));
+        assert_eq!(parts(2).unwrap()?, Answer::Num(2));
+        assert_eq!(PARSES.load(Ordering::Relaxed), 1);
+        Ok(())
+    }
+    #[test]
+    fn embedded() -> Result<()> {
+        let mut solver = Solver::new();
+        solver.add(1, 1, |input| Ok(Answer::from(input)));
+        solver.embed(1, "input.txt", "embedded input");
+        solver.embed(1, "test.txt", "embedded test");
+        solver.embed(1, "Cargo.toml", "embedded manifest");
+        let day_input = solver.day_input(1);
+        assert!(day_input.is_embedded());
+        assert_eq!(day_input.path(), PathBuf::from("src/day01/input.txt"));
+        let answer = |input| solver.parts(1, &input)(1).unwrap();
+        assert_eq!(answer(day_input)?, Answer::from("embedded input"));
+        let test = solver.file_input(1, PathBuf::from("elsewhere/test.txt"));
+        assert_eq!(answer(test)?, Answer::from("embedded test"));
+        // files on disk win
+        let manifest = solver.file_input(1, PathBuf::from("Cargo.toml"));
+        assert!(answer(manifest)?.to_string().starts_with("[package]"));
+        assert!(!solver.day_input(2).is_embedded());
+        solver.from_disk = true;
+        assert!(!solver.day_input(1).is_embedded());
+        Ok(())
+    }
+    #[test]
+    fn sources() -> Result<()> {
+        let mut solver = Solver::new();
+        solver.add(1, 1, |_| Ok(Answer::Num(0)));
+        solver.add_source(1, 1, |input| Ok(Answer::from(input.bytes()?.len())));
+        solver.add(1, 2, |input| Ok(Answer::from(input.len())));
+        let size = Answer::from(std::fs::metadata("Cargo.toml")?.len());
+        let input = InputFile::new("Cargo.toml");
+        assert_eq!(solver.parts(1, &input)(1).unwrap()?, size);
+        assert_eq!(solver.parts(1, &input)(2).unwrap()?, size);
+        assert!(solver.parts(1, &input)(3).is_none());
+        let missing = InputFile::new("missing.txt");
+        assert!(solver.parts(1, &missing)(1).unwrap().is_err());
+        assert!(solver.parts(1, &missing)(2).unwrap().is_err());
+        Ok(())
+    }
+}
@@ -0,0 +1,139 @@
+use std::{
+    borrow::Cow,
+    cell::Cell,
+    fs, io,
+    path::Path,
+    sync::{
+        atomic::{AtomicBool, AtomicU64, Ordering},
+        LazyLock, Mutex,
+    },
+    time::{Duration, Instant},
+};
+
+// Lightweight span instrumentation, recorded only after start() (e.g. by the runners'
+// --trace flag) and written in the Chrome trace-event format, which chrome://tracing,
+// Perfetto and speedscope can show. A span lasts until the guard is dropped:
+//
+//     let _span = trace::span("multi_mappings");
+//
+// Without start() a span costs an atomic load.
+
+static ENABLED: AtomicBool = AtomicBool::new(false);
+static EVENTS: Mutex<Vec<Event>> = Mutex::new(Vec::new());
+static EPOCH: LazyLock<Instant> = LazyLock::new(Instant::now);
+static NEXT_THREAD: AtomicU64 = AtomicU64::new(1);
+
+thread_local! {
+    static THREAD: Cell<u64> = const { Cell::new(0) };
+}
+
+struct Event {
+    name: Cow<'static, str>,
+    thread: u64,
+    start: Duration,
+    duration: Duration,
+}
+
+pub struct Span {
+    recording: Option<(Cow<'static, str>, Instant)>,
+}
+
+pub fn start() {
+    LazyLock::force(&EPOCH);
+    ENABLED.store(true, Ordering::Relaxed);
+}
+
+pub fn is_enabled() -> bool {
+    ENABLED.load(Ordering::Relaxed)
+}
+
+pub fn span(name: impl Into<Cow<'static, str>>) -> Span {
+    if !is_enabled() {
+        return Span { recording: None };
+    }
+    Span {
+        recording: Some((name.into(), Instant::now())),
+    }
+}
+
+impl Drop for Span {
+    fn drop(&mut self) {
+        let Some((name, start)) = self.recording.take() else {
+            return;
+        };
+        let event = Event {
+            name,
+            thread: thread_number(),
+            start: start.saturating_duration_since(*EPOCH),
+            duration: start.elapsed(),
+        };
+        EVENTS.lock().unwrap().push(event);
+    }
+}
+
+// small numbers instead of the opaque ThreadId
+fn thread_number() -> u64 {
+    THREAD.with(|thread| {
+        if thread.get() == 0 {
+            thread.set(NEXT_THREAD.fetch_add(1, Ordering::Relaxed));
+        }
+        thread.get()
+    })
+}
+
+// the spans recorded so far as complete ("X") events, timestamps in microseconds
+pub fn to_json() -> String {
+    let events = EVENTS.lock().unwrap();
+    let events: Vec<String> = events
+        .iter()
+        .map(|event| {
+            format!(
+                "{{\"name\":{},\"cat\":\"aoc\",\"ph\":\"X\",\"ts\":{:.3},\"dur\":{:.3},\"pid\":1,\"tid\":{}}}",
+                crate::json_string(&event.name),
+                event.start.as_secs_f64() * 1e6,
+                event.duration.as_secs_f64() * 1e6,
+                event.thread
+            )
+        })
+        .collect();
+    format!("{{\"traceEvents\":[\n{}\n]}}\n", events.join(",\n"))
+}
+
+pub fn write(file: &Path) -> io::Result<()> {
+    fs::write(file, to_json())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // the recorder is global, so everything is checked in one test
+    #[test]
+    fn spans() {
+        drop(span("before start"));
+        start();
+        {
+            let _outer = span("outer");
+            let _inner = span(format!("inner {}", 1));
+        }
+        std::thread::spawn(|| drop(span("other \"thread\"")))
+            .join()
+            .unwrap();
+        let json = to_json();
+        assert!(json.starts_with("{\"traceEvents\":[\n"));
+        // inner ends first
+        let inner = json
+            .find("{\"name\":\"inner 1\",\"cat\":\"aoc\",\"ph\":\"X\"")
+            .unwrap();
+        assert!(inner < json.find("\"name\":\"outer\"").unwrap());
+        assert!(json.contains("{\"name\":\"outer\",\"cat\":\"aoc\",\"ph\":\"X\",\"ts\":"));
+        assert!(json.contains("\"name\":\"other \\\"thread\\\"\""));
+        assert!(!json.contains("before start"));
+        // other tests may record spans as well once started
+        let threads: std::collections::HashSet<&str> = json
+            .lines()
+            .filter_map(|line| line.split("\"tid\":").nth(1))
+            .collect();
+        assert!(threads.len() >= 2);
+    }
+}
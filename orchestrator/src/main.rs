@@ -0,0 +1,21 @@
+use common::{
+    anyhow::Result,
+    orchestrator::{self, Orchestrator},
+};
+use std::{path::PathBuf, process::ExitCode};
+use structopt::StructOpt;
+
+#[derive(Debug, StructOpt)]
+struct Args {
+    /// package, workspace or directory of packages containing the day binaries
+    #[structopt(long, default_value = ".")]
+    root: PathBuf,
+    #[structopt(flatten)]
+    args: orchestrator::Args,
+}
+
+fn main() -> Result<ExitCode> {
+    let args = Args::from_args();
+    let mut orchestrator = Orchestrator::build(&args.root)?;
+    Ok(orchestrator.run(args.args))
+}
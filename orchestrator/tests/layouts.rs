@@ -0,0 +1,165 @@
+use common::{
+    anyhow::{anyhow, Result},
+    orchestrator::{run_day, Orchestrator},
+    Answer,
+};
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    env, fs,
+    path::{Path, PathBuf},
+    process::Command,
+};
+
+// Every day exists four times (see aoc_gen). This runs all layouts on the inputs and
+// examples in solutions/ and fails if any of them disagrees with the module per day
+// layout. A failing part (error or panic) only agrees with another failing part.
+
+const MODULE_LAYOUT: &str = "aoc_module_per_day/days";
+const BINARY_LAYOUTS: [&str; 3] = [
+    "aoc_workspace/days_ws",
+    "aoc_project_per_day",
+    "aoc_bin_per_day",
+];
+
+// (day, input file name, part) -> answer, None if the part failed
+type Answers = BTreeMap<(u8, String, u8), Option<Answer>>;
+
+fn repository_root() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("..")
+}
+
+// solutions/dayNN/*.txt by day
+fn shared_inputs(root: &Path) -> Result<BTreeMap<u8, Vec<PathBuf>>> {
+    let mut inputs = BTreeMap::new();
+    for entry in fs::read_dir(root.join("solutions"))? {
+        let dir = entry?.path();
+        let name = dir.file_name().unwrap().to_string_lossy().into_owned();
+        let Some(day) = name.strip_prefix("day").and_then(|n| n.parse().ok()) else {
+            continue;
+        };
+        let mut files = Vec::new();
+        for entry in fs::read_dir(&dir)? {
+            let file = entry?.path();
+            if file.extension().is_some_and(|e| e == "txt") {
+                files.push(file);
+            }
+        }
+        files.sort();
+        inputs.insert(day, files);
+    }
+    Ok(inputs)
+}
+
+fn file_name(file: &Path) -> String {
+    file.file_name().unwrap().to_string_lossy().into_owned()
+}
+
+fn binary_layout_answers(dir: &Path, inputs: &BTreeMap<u8, Vec<PathBuf>>) -> Result<Answers> {
+    let orchestrator = Orchestrator::build(dir)?;
+    let mut answers = Answers::new();
+    for (day, files) in inputs {
+        let Some(binary) = orchestrator.days().get(day) else {
+            continue;
+        };
+        for file in files {
+            for (part, record) in run_day(binary, file, None, false)? {
+                answers.insert((*day, file_name(file), part), record.answer.ok());
+            }
+        }
+    }
+    Ok(answers)
+}
+
+// the module per day layout has no day binaries, its Solver prints `dayNN partNN: ...`
+// as the last line (after anything the day printed itself)
+fn module_layout_answers(dir: &Path, inputs: &BTreeMap<u8, Vec<PathBuf>>) -> Result<Answers> {
+    let cargo = env::var("CARGO").unwrap_or(String::from("cargo"));
+    let mut answers = Answers::new();
+    for (day, files) in inputs {
+        for file in files {
+            for part in [1, 2] {
+                let output = Command::new(&cargo)
+                    .args([
+                        "run",
+                        "-q",
+                        "--",
+                        "--no-cache",
+                        "file",
+                        &day.to_string(),
+                        &part.to_string(),
+                    ])
+                    .arg(file)
+                    .current_dir(dir)
+                    .output()?;
+                let stderr = String::from_utf8_lossy(&output.stderr);
+                // unlike the day binaries the Solver doesn't catch panics
+                if !output.status.success() && stderr.contains("panicked at") {
+                    answers.insert((*day, file_name(file), part), None);
+                    continue;
+                }
+                if !output.status.success() {
+                    return Err(anyhow!(
+                        "running {} failed: {}\n{}",
+                        dir.display(),
+                        output.status,
+                        stderr
+                    ));
+                }
+                let stdout = String::from_utf8_lossy(&output.stdout);
+                let prefix = format!("day{:02} part{:02}: ", day, part);
+                let line = stdout.lines().last().unwrap_or("");
+                let line = line.strip_prefix(&prefix).unwrap_or(line);
+                let answer = if line.starts_with("Err") {
+                    None
+                } else {
+                    Some(line.parse().unwrap())
+                };
+                answers.insert((*day, file_name(file), part), answer);
+            }
+        }
+    }
+    Ok(answers)
+}
+
+fn show(answer: Option<&Option<Answer>>) -> String {
+    match answer {
+        Some(Some(answer)) => answer.to_string(),
+        Some(None) => String::from("failed"),
+        None => String::from("missing"),
+    }
+}
+
+#[test]
+fn layouts_agree() -> Result<()> {
+    let root = repository_root();
+    let inputs = shared_inputs(&root)?;
+    let expected = module_layout_answers(&root.join(MODULE_LAYOUT), &inputs)?;
+    assert!(!expected.is_empty(), "no answers from {}", MODULE_LAYOUT);
+    let mut disagreements = Vec::new();
+    for layout in BINARY_LAYOUTS {
+        let answers = binary_layout_answers(&root.join(layout), &inputs)?;
+        let keys: BTreeSet<_> = expected.keys().chain(answers.keys()).collect();
+        for key in keys {
+            let (ours, theirs) = (expected.get(key), answers.get(key));
+            if ours != theirs {
+                let (day, file, part) = key;
+                disagreements.push(format!(
+                    "day{:02} part{} {}: {} says {}, {} says {}",
+                    day,
+                    part,
+                    file,
+                    MODULE_LAYOUT,
+                    show(ours),
+                    layout,
+                    show(theirs)
+                ));
+            }
+        }
+    }
+    assert!(
+        disagreements.is_empty(),
+        "the layouts disagree:\n{}",
+        disagreements.join("\n")
+    );
+    Ok(())
+}
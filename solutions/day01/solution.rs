@@ -0,0 +1,140 @@
+use common::anyhow::Result;
+use common::matcher::Matcher;
+use common::{aoc, aoc_parser, Answer};
+use std::sync::LazyLock;
+
+pub fn part_one(input: &str) -> Result<Answer> {
+    let input = parse_input(input)?;
+    solve_one(&input)
+}
+
+pub fn part_two(input: &str) -> Result<Answer> {
+    let input = parse_input(input)?;
+    solve_two(&input)
+}
+
+struct Input<'a> {
+    lines: Vec<&'a str>,
+}
+
+#[aoc_parser]
+fn parse_input(input: &str) -> Result<Input<'_>> {
+    let lines: Vec<&str> = input.lines().collect();
+    Ok(Input { lines })
+}
+
+fn parse_line(line: &str) -> i128 {
+    let mut first = None;
+    let mut last = None;
+    for c in line.chars() {
+        if c.is_ascii_digit() {
+            if first.is_none() {
+                first = Some(c);
+            }
+            last = Some(c);
+        }
+    }
+    let fd = first.unwrap();
+    let ld = last.unwrap();
+    let ifd = fd.to_digit(10).unwrap() as i128;
+    let ild = ld.to_digit(10).unwrap() as i128;
+    ifd * 10 + ild
+}
+
+// spelled digits may overlap, e.g. "twone" ends with a one
+static DIGITS: LazyLock<Matcher<i128>> = LazyLock::new(|| {
+    Matcher::with_values([
+        ("one", 1),
+        ("two", 2),
+        ("three", 3),
+        ("four", 4),
+        ("five", 5),
+        ("six", 6),
+        ("seven", 7),
+        ("eight", 8),
+        ("nine", 9),
+        ("1", 1),
+        ("2", 2),
+        ("3", 3),
+        ("4", 4),
+        ("5", 5),
+        ("6", 6),
+        ("7", 7),
+        ("8", 8),
+        ("9", 9),
+    ])
+});
+
+fn parse_advanced(line: &str) -> i128 {
+    let first = DIGITS.first(line).unwrap();
+    let last = DIGITS.last(line).unwrap();
+    first.value * 10 + last.value
+}
+
+#[aoc(day = 1, part = 1)]
+fn solve_one(input: &Input) -> Result<Answer> {
+    let Input { lines } = input;
+
+    let mut sum = 0;
+    for line in lines {
+        sum += parse_line(line);
+    }
+    Ok(Answer::Num(sum))
+}
+
+#[aoc(day = 1, part = 2)]
+fn solve_two(input: &Input) -> Result<Answer> {
+    let Input { lines } = input;
+
+    let mut sum = 0;
+    for line in lines {
+        sum += parse_advanced(line);
+    }
+    Ok(Answer::Num(sum))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::test_utils::*;
+    use std::sync::LazyLock;
+    use test::Bencher;
+
+    static TEST: LazyLock<String> = local_file!("test.txt");
+    static TEST2: LazyLock<String> = local_file!("test2.txt");
+    static INPUT: LazyLock<String> = local_file!("input.txt");
+
+    #[test]
+    fn test_one() -> Result<()> {
+        let answer = super::part_one(&TEST)?;
+        assert_eq!(answer, Answer::Num(142));
+        Ok(())
+    }
+    #[test]
+    fn part_one() -> Result<()> {
+        let answer = super::part_one(&INPUT)?;
+        assert_eq!(answer, Answer::Num(54450));
+        Ok(())
+    }
+    #[test]
+    fn test_two() -> Result<()> {
+        let answer = super::part_two(&TEST2)?;
+        assert_eq!(answer, Answer::Num(281));
+        Ok(())
+    }
+    #[test]
+    fn part_two() -> Result<()> {
+        let answer = super::part_two(&INPUT)?;
+        assert_eq!(answer, Answer::Num(54265));
+        Ok(())
+    }
+
+    #[bench]
+    fn bench_part_one(b: &mut Bencher) {
+        b.iter(part_one)
+    }
+    #[bench]
+    fn bench_part_two(b: &mut Bencher) {
+        b.iter(part_two)
+    }
+}